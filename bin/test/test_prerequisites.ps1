@@ -34,6 +34,21 @@ if ($LASTEXITCODE) { Throw }
 sleep 5
 if (netstat -ano | Select-String LISTENING | Select-string 127.0.0.1:8003) {write-host -foregroundcolor Green "server-ssl-client-authent up"} else {write-host -foregroundcolor Red "server-ssl-client-authent is down" ; cat build\server-ssl-client-authent.log ; exit 1}
 
+python ssl/proxy.py 8004 ssl/server/cert.selfsigned.pem 2>&1 > build\proxy-ssl.log &
+if ($LASTEXITCODE) { Throw }
+sleep 5
+if (netstat -ano | Select-String LISTENING | Select-string 127.0.0.1:8004) {write-host -foregroundcolor Green "proxy-ssl up"} else {write-host -foregroundcolor Red "proxy-ssl is down" ; cat build\proxy-ssl.log ; exit 1}
+
+python ssl/tls_session.py 8005 ssl/server/cert.selfsigned.pem 2>&1 > build\server-ssl-tls-session.log &
+if ($LASTEXITCODE) { Throw }
+sleep 5
+if (netstat -ano | Select-String LISTENING | Select-string 127.0.0.1:8005) {write-host -foregroundcolor Green "server-ssl-tls-session up"} else {write-host -foregroundcolor Red "server-ssl-tls-session is down" ; cat build\server-ssl-tls-session.log ; exit 1}
+
+python raw_server.py 8007 2>&1 > build\server-raw.log &
+if ($LASTEXITCODE) { Throw }
+sleep 5
+if (netstat -ano | Select-String LISTENING | Select-string 127.0.0.1:8007) {write-host -foregroundcolor Green "server-raw up"} else {write-host -foregroundcolor Red "server-raw is down" ; cat build\server-raw.log ; exit 1}
+
 Get-ChildItem -Force C:\Squid\bin
 write-output "cache deny all" "cache_log /dev/null" "access_log /dev/null" "http_access allow all" "http_port 0.0.0.0:3128" "request_header_add From-Proxy Hello" "reply_header_add From-Proxy Hello" > squid.conf
 C:\Squid\bin\squid -k kill 2>&1 || true
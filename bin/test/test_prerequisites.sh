@@ -61,6 +61,22 @@ echo -e "\n------------------ Starting ssl/server.py (Self-signed certificate +
 nohup python3 ssl/server.py 8003 ssl/server/cert.selfsigned.pem true > build/server-ssl-client-authent.log 2>&1 &
 check_listen_port "ssl/server.py" 8003 || cat_and_exit_err build/server-ssl-client-authent.log
 
+echo -e "\n------------------ Starting ssl/proxy.py (HTTPS proxy, self-signed certificate)"
+python3 ssl/proxy.py 8004 ssl/server/cert.selfsigned.pem > build/proxy-ssl.log 2>&1 &
+check_listen_port "ssl/proxy.py" 8004 || cat_and_exit_err build/proxy-ssl.log
+
+echo -e "\n------------------ Starting ssl/tls_session.py (TLS session resumption, self-signed certificate)"
+python3 ssl/tls_session.py 8005 ssl/server/cert.selfsigned.pem > build/server-ssl-tls-session.log 2>&1 &
+check_listen_port "ssl/tls_session.py" 8005 || cat_and_exit_err build/server-ssl-tls-session.log
+
+echo -e "\n------------------ Starting ssl/ocsp_server.sh (OCSP stapling, signed by a generated CA)"
+ssl/ocsp_server.sh 8006 > build/server-ssl-ocsp.log 2>&1 &
+check_listen_port "ssl/ocsp_server.sh" 8006 || cat_and_exit_err build/server-ssl-ocsp.log
+
+echo -e "\n------------------ Starting raw_server.py (raw HTTP responses)"
+python3 raw_server.py 8007 > build/server-raw.log 2>&1 &
+check_listen_port "raw_server.py" 8007 || cat_and_exit_err build/server-raw.log
+
 echo -e "\n------------------ Starting squid (proxy)"
 if [ -f /var/run/squid.pid ] ; then
   sudo squid -k shutdown || true
@@ -0,0 +1,35 @@
+#!/usr/bin/env python
+# usage: ./raw_server.py <port>
+# Start an HTTP server sending a raw response with a Content-Length header less than its body
+# length. Headers and body are sent in a single write, so that libcurl reads the excess bytes
+# with the headers (excess bytes read alone are silently dropped by libcurl).
+import socketserver
+import sys
+
+RESPONSE = (
+    b"HTTP/1.1 200 OK\r\n"
+    b"Content-Type: text/plain\r\n"
+    b"Content-Length: 5\r\n"
+    b"Connection: close\r\n"
+    b"\r\n"
+    b"Hello World!"
+)
+
+
+class RawHandler(socketserver.StreamRequestHandler):
+    def handle(self):
+        # Reads the request headers before answering.
+        while self.rfile.readline() not in (b"\r\n", b"\n", b""):
+            pass
+        self.wfile.write(RESPONSE)
+
+
+class RawServer(socketserver.ThreadingTCPServer):
+    allow_reuse_address = True
+    daemon_threads = True
+
+
+if __name__ == "__main__":
+    port = int(sys.argv[1])
+    with RawServer(("127.0.0.1", port), RawHandler) as server:
+        server.serve_forever()
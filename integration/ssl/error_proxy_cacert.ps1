@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl ssl/error_proxy_cacert.hurl --proxy https://localhost:8004 --proxy-cacert ssl/server/cert.selfsigned.pem
@@ -0,0 +1,30 @@
+#!/bin/bash
+# usage: ./ocsp_server.sh <port>
+# Start an HTTPS server stapling a good OCSP response for its certificate. The CA, the server
+# certificate and the OCSP response are generated in build/ocsp so they're never expired.
+set -Eeuo pipefail
+port="$1"
+dir=build/ocsp
+mkdir -p "$dir"
+
+openssl req -x509 -newkey rsa:2048 -nodes -days 30 -subj "/CN=ocspCA" \
+    -keyout "$dir/ca.key" -out "$dir/ca.pem"
+openssl req -newkey rsa:2048 -nodes -subj "/CN=localhost" \
+    -keyout "$dir/server.key" -out "$dir/server.csr"
+printf "subjectAltName=DNS:localhost\n" > "$dir/server.ext"
+openssl x509 -req -days 30 -in "$dir/server.csr" -extfile "$dir/server.ext" \
+    -CA "$dir/ca.pem" -CAkey "$dir/ca.key" -CAcreateserial -out "$dir/server.pem"
+
+# The OCSP responder database, with the server certificate as valid.
+serial=$(openssl x509 -in "$dir/server.pem" -noout -serial | cut -d= -f2)
+expire=$(openssl x509 -in "$dir/server.pem" -noout -enddate | cut -d= -f2)
+expire=$(date -u -d "$expire" +%y%m%d%H%M%SZ)
+printf "V\t%s\t\t%s\tunknown\t/CN=localhost\n" "$expire" "$serial" > "$dir/index.txt"
+openssl ocsp -index "$dir/index.txt" -ndays 30 \
+    -CA "$dir/ca.pem" -rsigner "$dir/ca.pem" -rkey "$dir/ca.key" \
+    -issuer "$dir/ca.pem" -cert "$dir/server.pem" -respout "$dir/response.der"
+
+# The CA is sent in the chain: libcurl needs the issuer to check the OCSP response.
+exec openssl s_server -accept "$port" -www \
+    -cert "$dir/server.pem" -key "$dir/server.key" -cert_chain "$dir/ca.pem" \
+    -status_file "$dir/response.der"
@@ -0,0 +1,73 @@
+#!/usr/bin/env python
+# usage: ./proxy.py <port> <cert_file>
+# Start an HTTPS proxy, only supporting tunneling with the CONNECT method
+import select
+import socket
+import socketserver
+import ssl
+import sys
+
+
+class ProxyHandler(socketserver.StreamRequestHandler):
+    def handle(self):
+        request_line = self.rfile.readline().decode("iso-8859-1")
+        # Skip the request headers.
+        while self.rfile.readline() not in (b"\r\n", b"\n", b""):
+            pass
+
+        method, target, _ = request_line.split(" ", 2)
+        if method != "CONNECT":
+            self.wfile.write(b"HTTP/1.1 405 Method Not Allowed\r\n\r\n")
+            return
+        host, port = target.rsplit(":", 1)
+        upstream = socket.create_connection((host, int(port)))
+        self.wfile.write(b"HTTP/1.1 200 Connection established\r\n\r\n")
+        self.wfile.flush()
+        tunnel(self.connection, upstream)
+        upstream.close()
+
+
+def tunnel(client, upstream):
+    sockets = [client, upstream]
+    while True:
+        readable, _, _ = select.select(sockets, [], [])
+        for s in readable:
+            data = s.recv(65536)
+            if not data:
+                return
+            other = upstream if s is client else client
+            other.sendall(data)
+
+
+class ProxyServer(socketserver.ThreadingTCPServer):
+    allow_reuse_address = True
+    daemon_threads = True
+
+    def __init__(self, port, cert_file):
+        super().__init__(("127.0.0.1", port), ProxyHandler)
+        ssl_context = ssl.SSLContext(ssl.PROTOCOL_TLS_SERVER)
+        ssl_context.load_cert_chain(cert_file, "ssl/server/key.pem")
+        self.socket = ssl_context.wrap_socket(self.socket, server_side=True)
+
+
+def print_usage_and_exit():
+    print("usage: ./proxy.py <port> <cert_file>")
+    sys.exit(1)
+
+
+def main():
+    if len(sys.argv) < 3:
+        print_usage_and_exit()
+
+    port = int(sys.argv[1])
+    cert_file = sys.argv[2]
+
+    print("Starting HTTPS Proxy")
+    print("  port: " + str(port))
+    print("  cert file: " + cert_file)
+    with ProxyServer(port, cert_file) as server:
+        server.serve_forever()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl ssl/proxy_cacert.hurl --proxy https://localhost:8004 --proxy-cacert ssl/server/cert.selfsigned.pem --cacert ssl/ca/cert.pem --ssl-no-revoke --verbose
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl ssl/proxy_cacert.hurl --proxy https://localhost:8004 --proxy-cacert ssl/server/cert.selfsigned.pem --cacert ssl/ca/cert.pem --verbose
@@ -0,0 +1,62 @@
+#!/usr/bin/env python
+# usage: ./tls_session.py <port> <cert_file>
+# Start an HTTPS server closing the connection after each response. TLS connections are
+# properly shut down, so their TLS sessions can be resumed by the next connections.
+import http.server
+import ssl
+import sys
+
+
+class HelloHandler(http.server.BaseHTTPRequestHandler):
+    protocol_version = "HTTP/1.1"
+
+    def do_GET(self):
+        body = b"Hello World!"
+        self.send_response(200)
+        self.send_header("Content-Type", "text/plain")
+        self.send_header("Content-Length", str(len(body)))
+        self.send_header("Connection", "close")
+        self.end_headers()
+        self.wfile.write(body)
+
+
+class TlsSessionServer(http.server.ThreadingHTTPServer):
+    allow_reuse_address = True
+    daemon_threads = True
+
+    def __init__(self, port, cert_file):
+        super().__init__(("127.0.0.1", port), HelloHandler)
+        ssl_context = ssl.SSLContext(ssl.PROTOCOL_TLS_SERVER)
+        ssl_context.load_cert_chain(cert_file, "ssl/server/key.pem")
+        self.socket = ssl_context.wrap_socket(self.socket, server_side=True)
+
+    def shutdown_request(self, request):
+        # A TLS session whose connection has not been shut down can't be resumed.
+        try:
+            request.unwrap()
+        except (OSError, ssl.SSLError):
+            pass
+        super().shutdown_request(request)
+
+
+def print_usage_and_exit():
+    print("usage: ./tls_session.py <port> <cert_file>")
+    sys.exit(1)
+
+
+def main():
+    if len(sys.argv) < 3:
+        print_usage_and_exit()
+
+    port = int(sys.argv[1])
+    cert_file = sys.argv[2]
+
+    print("Starting TLS Session Server")
+    print("  port: " + str(port))
+    print("  cert file: " + cert_file)
+    with TlsSessionServer(port, cert_file) as server:
+        server.serve_forever()
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl ssl/verify_status.hurl --cacert build/ocsp/ca.pem --verbose
@@ -0,0 +1,10 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-exactly-keys")
+def error_assert_exactly_keys():
+    return Response(
+        '{ "user": { "id": 1, "name": "Bob", "role": "admin" } }',
+        mimetype="application/json",
+    )
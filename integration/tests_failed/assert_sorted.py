@@ -0,0 +1,9 @@
+from app import app
+from flask import Response
+
+
+@app.route("/error-assert-sorted")
+def error_assert_sorted():
+    return Response(
+        '{ "ids": [3, 1, 2], "mixed": [1, "2", 3] }', mimetype="application/json"
+    )
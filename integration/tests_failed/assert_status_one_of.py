@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-status-one-of/error")
+def assert_status_one_of_error():
+    return Response("", status=500)
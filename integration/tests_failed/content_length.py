@@ -0,0 +1,9 @@
+from app import app
+from flask import Response
+
+
+@app.route("/content-length/long")
+def content_length_long():
+    response = Response("Hello")
+    response.headers["Content-Length"] = "12"
+    return response
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --data-urlencode name=Bob tests_failed/data_urlencode.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --data-urlencode name=Bob tests_failed/data_urlencode.hurl
@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/report.md) {
+    Remove-Item build/report.md
+}
+hurl --test --report-markdown build/report.md tests_ok/test.1.hurl tests_failed/assert_sorted.hurl
+$exit_code = $LASTEXITCODE
+Write-Host (Get-Content build/report.md -Raw) -NoNewLine
+exit $exit_code
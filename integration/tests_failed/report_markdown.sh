@@ -0,0 +1,7 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/report.md
+exit_code=0
+hurl --test --report-markdown build/report.md tests_ok/test.1.hurl tests_failed/assert_sorted.hurl || exit_code=$?
+cat build/report.md
+exit "$exit_code"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variable-cmd 'token=exit 3' tests_ok/variable_cmd.hurl
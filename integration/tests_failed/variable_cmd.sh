@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable-cmd 'token=exit 3' tests_ok/variable_cmd.hurl
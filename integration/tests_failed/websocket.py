@@ -0,0 +1,11 @@
+from app import app
+from flask import Response
+
+
+@app.route("/websocket-invalid-accept", websocket=True)
+def websocket_invalid_accept():
+    response = Response(status=101)
+    response.headers["Upgrade"] = "websocket"
+    response.headers["Connection"] = "Upgrade"
+    response.headers["Sec-WebSocket-Accept"] = "xxx"
+    return response
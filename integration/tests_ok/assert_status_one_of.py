@@ -0,0 +1,7 @@
+from app import app
+from flask import Response
+
+
+@app.route("/assert-status-one-of")
+def assert_status_one_of():
+    return Response("", status=204, headers={"X-Status": "empty"})
@@ -0,0 +1,14 @@
+import gzip
+
+from app import app
+from flask import make_response, request
+
+
+@app.route("/compression-ratio")
+def compression_ratio():
+    data = b"Hello World! " * 200
+    if "gzip" not in request.headers.get("Accept-Encoding", ""):
+        return make_response(data)
+    resp = make_response(gzip.compress(data))
+    resp.headers["Content-Encoding"] = "gzip"
+    return resp
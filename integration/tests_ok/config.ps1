@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --config tests_ok/config.toml --user-agent cli-agent tests_ok/config.hurl
@@ -0,0 +1,12 @@
+from flask import redirect, request
+from app import app
+
+
+@app.route("/config/redirect")
+def config_redirect():
+    return redirect("http://localhost:8000/config")
+
+
+@app.route("/config")
+def config():
+    return request.headers["User-Agent"]
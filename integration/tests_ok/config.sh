@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --config tests_ok/config.toml --user-agent cli-agent tests_ok/config.hurl
@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/content-length")
+def content_length():
+    return "Hello World!"
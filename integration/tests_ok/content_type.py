@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/content-type/json")
+def content_type_json():
+    return Response('{"id": 1}', mimetype="application/json")
+
+
+@app.route("/content-type/html")
+def content_type_html():
+    return "<p>Hello</p>"
@@ -0,0 +1,11 @@
+from app import app
+from flask import make_response
+
+
+@app.route("/cookie-nth")
+def cookie_nth():
+    resp = make_response()
+    resp.set_cookie("first", "one")
+    resp.set_cookie("second", "two", httponly=True)
+    resp.set_cookie("third", "three")
+    return resp
@@ -129,3 +129,13 @@ def set_cookies():
         httponly=True,
     )
     return resp
+
+
+@app.route("/cookies/set-scoped")
+def set_scoped_cookie():
+    resp = make_response()
+    resp.headers["Set-Cookie"] = (
+        "scoped=abc; domain=localhost; path=/api; expires=Wed, 21 Oct 2015 07:28:00 GMT; "
+        "max-age=60; secure; httponly; samesite=Strict"
+    )
+    return resp
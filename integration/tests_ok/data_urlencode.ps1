@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --data-urlencode 'name=Bob Smith & co' --data-urlencode 'expr=1+1=2' --data-urlencode '=été' tests_ok/data_urlencode.1.hurl tests_ok/data_urlencode.2.hurl tests_ok/data_urlencode.3.hurl
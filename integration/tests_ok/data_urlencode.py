@@ -0,0 +1,27 @@
+from app import app
+from flask import request
+
+
+@app.route("/data-urlencode", methods=["POST"])
+def data_urlencode():
+    assert request.content_type == "application/x-www-form-urlencoded"
+    assert request.get_data() == b"name=Bob%20Smith%20%26%20co&expr=1%2B1%3D2&%C3%A9t%C3%A9"
+    assert request.form["name"] == "Bob Smith & co"
+    assert request.form["expr"] == "1+1=2"
+    assert "été" in request.form
+    return ""
+
+
+@app.route("/data-urlencode/get")
+def data_urlencode_get():
+    assert request.method == "GET"
+    assert request.content_type == "application/x-www-form-urlencoded"
+    assert request.get_data() == b"name=Bob%20Smith%20%26%20co&expr=1%2B1%3D2&%C3%A9t%C3%A9"
+    return ""
+
+
+@app.route("/data-urlencode/body", methods=["POST"])
+def data_urlencode_body():
+    assert request.content_type == "text/plain"
+    assert request.get_data() == b"Hello"
+    return ""
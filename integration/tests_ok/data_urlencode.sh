@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --data-urlencode 'name=Bob Smith & co' --data-urlencode 'expr=1+1=2' --data-urlencode '=été' tests_ok/data_urlencode.1.hurl tests_ok/data_urlencode.2.hurl tests_ok/data_urlencode.3.hurl
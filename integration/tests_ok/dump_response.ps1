@@ -0,0 +1,7 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/dump_response.http) {
+    Remove-Item build/dump_response.http
+}
+hurl --dump-response build/dump_response.http --no-output tests_ok/dump_response.hurl
+Write-Host (Get-Content build/dump_response.http -Raw) -NoNewLine
@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/dump-response/hello")
+def dump_response_hello():
+    return Response("Hello World!", headers={"X-Dump": "hello"})
+
+
+@app.route("/dump-response/json")
+def dump_response_json():
+    return Response('{"id":1}', status=201, mimetype="application/json")
@@ -0,0 +1,5 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -f build/dump_response.http
+hurl --dump-response build/dump_response.http --no-output tests_ok/dump_response.hurl
+cat build/dump_response.http
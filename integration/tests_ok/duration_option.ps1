@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/duration_option.hurl --connect-timeout 30s --max-time 1m --delay 0ms --retry-interval 500ms
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/duration_option.hurl --connect-timeout 30s --max-time 1m --delay 0ms --retry-interval 500ms
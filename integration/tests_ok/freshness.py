@@ -0,0 +1,43 @@
+import time
+from email.utils import formatdate
+
+from app import app
+from flask import Response
+
+
+@app.route("/freshness/fresh")
+def freshness_fresh():
+    return Response(
+        "Hello", headers={"Cache-Control": "public, max-age=3600", "Age": "600"}
+    )
+
+
+@app.route("/freshness/stale")
+def freshness_stale():
+    return Response("Hello", headers={"Cache-Control": "max-age=60", "Age": "120"})
+
+
+@app.route("/freshness/no-max-age")
+def freshness_no_max_age():
+    return Response("Hello", headers={"Cache-Control": "no-cache"})
+
+
+@app.route("/freshness/s-maxage")
+def freshness_s_maxage():
+    return Response(
+        "Hello",
+        headers={"Cache-Control": "max-age=3600, s-maxage=60", "Age": "120"},
+    )
+
+
+@app.route("/freshness/expires")
+def freshness_expires():
+    now = int(time.time())
+    return Response(
+        "Hello",
+        headers={
+            "Date": formatdate(now, usegmt=True),
+            "Expires": formatdate(now + 3600, usegmt=True),
+            "Age": "600",
+        },
+    )
@@ -0,0 +1,9 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+if (Test-Path build/output_dir) {
+    Remove-Item -Recurse build/output_dir
+}
+hurl --output-dir build/output_dir --no-output tests_ok/output_dir.hurl
+Write-Host (Get-Content build/output_dir/manifest.json -Raw) -NoNewLine
+Write-Host (Get-Content build/output_dir/1-1.body -Raw)
+Write-Host (Get-Content build/output_dir/1-2.body -Raw) -NoNewLine
@@ -0,0 +1,22 @@
+from app import app
+from flask import Response
+
+
+@app.route("/output-dir/hello")
+def output_dir_hello():
+    return "Hello World!"
+
+
+@app.route("/output-dir/redirect")
+def output_dir_redirect():
+    return Response(status=302, headers={"Location": "/output-dir/json"})
+
+
+@app.route("/output-dir/json")
+def output_dir_json():
+    return Response('{"id":1}', mimetype="application/json")
+
+
+@app.route("/output-dir/empty")
+def output_dir_empty():
+    return Response(status=204)
@@ -0,0 +1,8 @@
+#!/bin/bash
+set -Eeuo pipefail
+rm -rf build/output_dir
+hurl --output-dir build/output_dir --no-output tests_ok/output_dir.hurl
+cat build/output_dir/manifest.json
+cat build/output_dir/1-1.body
+echo
+cat build/output_dir/1-2.body
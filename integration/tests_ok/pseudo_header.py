@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/pseudo-header")
+def pseudo_header():
+    return "Hello World!"
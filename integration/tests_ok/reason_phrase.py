@@ -0,0 +1,12 @@
+from app import app
+from flask import Response
+
+
+@app.route("/reason-phrase/not-found")
+def reason_phrase_not_found():
+    return Response("Not Found", status=404)
+
+
+@app.route("/reason-phrase/custom")
+def reason_phrase_custom():
+    return Response("Hello", status="200 All Good")
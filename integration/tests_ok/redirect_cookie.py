@@ -0,0 +1,12 @@
+from app import app
+from flask import make_response, redirect, request
+
+
+@app.route("/redirect-cookie")
+def redirect_cookie():
+    # A first request sets a session cookie and redirects to itself.
+    if "session" not in request.cookies:
+        response = redirect("http://localhost:8000/redirect-cookie")
+        response.set_cookie("session", "1234")
+        return response
+    return make_response("Logged in!")
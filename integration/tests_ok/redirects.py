@@ -0,0 +1,17 @@
+from app import app
+from flask import redirect
+
+
+@app.route("/redirects/301-302")
+def redirects_301_302():
+    return redirect("http://localhost:8000/redirects/302", code=301)
+
+
+@app.route("/redirects/302")
+def redirects_302():
+    return redirect("http://localhost:8000/redirects/end", code=302)
+
+
+@app.route("/redirects/end")
+def redirects_end():
+    return "Redirects end!"
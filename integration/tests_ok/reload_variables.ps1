@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+Set-Content -Path build/reload_variables.env -Value 'token=old'
+hurl --variables-file build/reload_variables.env --reload-variables --output build/reload_variables.env tests_ok/reload_variables.1.hurl tests_ok/reload_variables.2.hurl
@@ -0,0 +1,14 @@
+from app import app
+from flask import request
+
+
+@app.route("/reload-variables/rotate")
+def reload_variables_rotate():
+    assert request.args.get("token") == "old"
+    return "token=new\n"
+
+
+@app.route("/reload-variables/check")
+def reload_variables_check():
+    assert request.args.get("token") == "new"
+    return ""
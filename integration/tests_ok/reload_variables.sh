@@ -0,0 +1,4 @@
+#!/bin/bash
+set -Eeuo pipefail
+echo 'token=old' > build/reload_variables.env
+hurl --variables-file build/reload_variables.env --reload-variables --output build/reload_variables.env tests_ok/reload_variables.1.hurl tests_ok/reload_variables.2.hurl
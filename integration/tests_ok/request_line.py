@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/request-line")
+def request_line():
+    return "Hello"
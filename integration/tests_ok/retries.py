@@ -0,0 +1,20 @@
+from app import app
+
+flaky_count = 0
+
+
+@app.route("/retries/reset", methods=["POST"])
+def retries_reset():
+    global flaky_count
+    flaky_count = 0
+    return ""
+
+
+# Return 500 on the first two calls, then 200
+@app.route("/retries/flaky")
+def retries_flaky():
+    global flaky_count
+    flaky_count += 1
+    if flaky_count <= 2:
+        return "", 500
+    return "OK", 200
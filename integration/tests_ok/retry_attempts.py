@@ -0,0 +1,20 @@
+from app import app
+
+attempts_count = 0
+
+
+@app.route("/retry-attempts/reset", methods=["POST"])
+def retry_attempts_reset():
+    global attempts_count
+    attempts_count = 0
+    return ""
+
+
+# Return 503 on the first call, then 200
+@app.route("/retry-attempts")
+def retry_attempts():
+    global attempts_count
+    attempts_count += 1
+    if attempts_count == 1:
+        return "", 503
+    return "OK", 200
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --test --slow-threshold 250 tests_ok/slow_threshold.hurl
@@ -0,0 +1,14 @@
+import time
+
+from app import app
+
+
+@app.route("/slow-threshold/fast")
+def slow_threshold_fast():
+    return "Fast"
+
+
+@app.route("/slow-threshold/slow")
+def slow_threshold_slow():
+    time.sleep(0.5)
+    return "Slow"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --test --slow-threshold 250 tests_ok/slow_threshold.hurl
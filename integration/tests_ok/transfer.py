@@ -0,0 +1,6 @@
+from app import app
+
+
+@app.route("/transfer", methods=["GET", "POST"])
+def transfer():
+    return "Hello World!"
@@ -0,0 +1,3 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl --variable-cmd 'token=echo s3cr3t' --variable-cmd 'id=echo 42' tests_ok/variable_cmd.hurl
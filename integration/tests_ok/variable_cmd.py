@@ -0,0 +1,8 @@
+from app import app
+from flask import request
+
+
+@app.route("/variable-cmd")
+def variable_cmd():
+    assert request.headers["Authorization"] == "Bearer s3cr3t"
+    return "Hello 42"
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl --variable-cmd 'token=echo s3cr3t' --variable-cmd 'id=echo 42' tests_ok/variable_cmd.hurl
@@ -0,0 +1,3 @@
+#!/bin/bash
+set -Eeuo pipefail
+hurl tests_ok/variables_order.hurl --verbose --variable zeta=1 --variable alpha=2 --variable mid=3 --variable beta=4
@@ -0,0 +1,4 @@
+Set-StrictMode -Version latest
+$ErrorActionPreference = 'Stop'
+hurl tests_ok/variables_precedence.hurl --variable id=1 --variables-file tests_ok/variables_precedence.properties
+hurl tests_ok/variables_precedence.hurl --variables-file tests_ok/variables_precedence.properties --variable id=1
@@ -0,0 +1,18 @@
+import base64
+import hashlib
+
+from app import app
+from flask import request, Response
+
+WEBSOCKET_GUID = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11"
+
+
+@app.route("/websocket", websocket=True)
+def websocket():
+    key = request.headers["Sec-WebSocket-Key"]
+    digest = hashlib.sha1((key + WEBSOCKET_GUID).encode()).digest()
+    response = Response(status=101)
+    response.headers["Upgrade"] = "websocket"
+    response.headers["Connection"] = "Upgrade"
+    response.headers["Sec-WebSocket-Accept"] = base64.b64encode(digest).decode()
+    return response
@@ -17,6 +17,8 @@
  */
 use clap::{value_parser, ArgAction};
 
+use super::duration;
+
 pub fn aws_sigv4() -> clap::Arg {
     clap::Arg::new("aws_sigv4")
         .long("aws-sigv4")
@@ -65,13 +67,21 @@ pub fn compressed() -> clap::Arg {
         .action(ArgAction::SetTrue)
 }
 
+pub fn config_file() -> clap::Arg {
+    clap::Arg::new("config_file")
+        .long("config")
+        .value_name("FILE")
+        .help("Read default options from the TOML FILE (default ~/.hurl.toml)")
+        .num_args(1)
+}
+
 pub fn connect_timeout() -> clap::Arg {
     clap::Arg::new("connect_timeout")
         .long("connect-timeout")
-        .value_name("SECONDS")
-        .help("Maximum time allowed for connection")
+        .value_name("DURATION")
+        .help("Maximum time allowed for connection (default unit: seconds)")
         .default_value("300".to_string())
-        .value_parser(value_parser!(u64))
+        .value_parser(duration::parse_seconds)
         .num_args(1)
 }
 
@@ -110,13 +120,31 @@ pub fn cookies_output_file() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn data_urlencode() -> clap::Arg {
+    clap::Arg::new("data_urlencode")
+        .long("data-urlencode")
+        .value_name("DATA")
+        .help("URL-encode DATA and send it as the body of the only entry of the file")
+        .action(ArgAction::Append)
+        .number_of_values(1)
+        .num_args(1)
+}
+
 pub fn delay() -> clap::Arg {
     clap::Arg::new("delay")
         .long("delay")
-        .value_name("milliseconds")
-        .help("Sets delay before each request.")
+        .value_name("DURATION")
+        .help("Sets delay before each request (default unit: milliseconds)")
         .default_value("0")
-        .value_parser(value_parser!(u64))
+        .value_parser(duration::parse_milliseconds)
+        .num_args(1)
+}
+
+pub fn dump_response() -> clap::Arg {
+    clap::Arg::new("dump_response")
+        .long("dump-response")
+        .value_name("FILE")
+        .help("Write every response in raw HTTP wire format to FILE")
         .num_args(1)
 }
 
@@ -223,11 +251,11 @@ pub fn max_time() -> clap::Arg {
     clap::Arg::new("max_time")
         .long("max-time")
         .short('m')
-        .value_name("SECONDS")
-        .help("Maximum time allowed for the transfer")
+        .value_name("DURATION")
+        .help("Maximum time allowed for the transfer (default unit: seconds)")
         .default_value("300".to_string())
         .allow_hyphen_values(true)
-        .value_parser(value_parser!(u64))
+        .value_parser(duration::parse_seconds)
         .num_args(1)
 }
 
@@ -264,6 +292,14 @@ pub fn output() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn output_dir() -> clap::Arg {
+    clap::Arg::new("output_dir")
+        .long("output-dir")
+        .value_name("DIR")
+        .help("Write every response body and a manifest.json index to DIR")
+        .num_args(1)
+}
+
 pub fn path_as_is() -> clap::Arg {
     clap::Arg::new("path_as_is")
         .long("path-as-is")
@@ -288,6 +324,14 @@ pub fn report_junit() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn report_markdown() -> clap::Arg {
+    clap::Arg::new("markdown")
+        .long("report-markdown")
+        .value_name("FILE")
+        .help("Write a Markdown report to FILE")
+        .num_args(1)
+}
+
 pub fn report_tap() -> clap::Arg {
     clap::Arg::new("tap")
         .long("report-tap")
@@ -296,6 +340,21 @@ pub fn report_tap() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn proxy_cacert_file() -> clap::Arg {
+    clap::Arg::new("proxy_cacert_file")
+        .long("proxy-cacert")
+        .value_name("FILE")
+        .help("CA certificate to verify the HTTPS proxy against (PEM format)")
+        .num_args(1)
+}
+
+pub fn reload_ca_store() -> clap::Arg {
+    clap::Arg::new("reload_ca_store")
+        .long("reload-ca-store")
+        .help("Re-read the CA certificates store for each connection instead of caching it")
+        .action(ArgAction::SetTrue)
+}
+
 pub fn report_html() -> clap::Arg {
     clap::Arg::new("report_html")
         .long("report-html")
@@ -304,6 +363,13 @@ pub fn report_html() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn reload_variables() -> clap::Arg {
+    clap::Arg::new("reload_variables")
+        .long("reload-variables")
+        .help("Re-read the variables files before running each Hurl file")
+        .action(ArgAction::SetTrue)
+}
+
 pub fn resolve() -> clap::Arg {
     clap::Arg::new("resolve")
         .long("resolve")
@@ -328,13 +394,22 @@ pub fn retry() -> clap::Arg {
 pub fn retry_interval() -> clap::Arg {
     clap::Arg::new("retry_interval")
         .long("retry-interval")
-        .value_name("MILLISECONDS")
-        .help("Interval in milliseconds before a retry")
-        .value_parser(value_parser!(u64))
+        .value_name("DURATION")
+        .help("Interval before a retry (default unit: milliseconds)")
+        .value_parser(duration::parse_milliseconds)
         .default_value("1000")
         .num_args(1)
 }
 
+pub fn slow_threshold() -> clap::Arg {
+    clap::Arg::new("slow_threshold")
+        .long("slow-threshold")
+        .value_name("DURATION")
+        .help("Warn about entries taking more than DURATION (default unit: milliseconds)")
+        .value_parser(duration::parse_milliseconds)
+        .num_args(1)
+}
+
 pub fn ssl_no_revoke() -> clap::Arg {
     clap::Arg::new("ssl_no_revoke")
         .long("ssl-no-revoke")
@@ -388,6 +463,16 @@ pub fn variable() -> clap::Arg {
         .num_args(1)
 }
 
+pub fn variable_cmd() -> clap::Arg {
+    clap::Arg::new("variable_cmd")
+        .long("variable-cmd")
+        .value_name("NAME=COMMAND")
+        .help("Define a variable from the standard output of a command")
+        .action(ArgAction::Append)
+        .number_of_values(1)
+        .num_args(1)
+}
+
 pub fn variables_file() -> clap::Arg {
     clap::Arg::new("variables_file")
         .long("variables-file")
@@ -0,0 +1,230 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use super::OptionsError;
+
+/// The config file used when no `--config` option is given, looked up in the user's home directory.
+pub const DEFAULT_CONFIG_FILENAME: &str = ".hurl.toml";
+
+/// Returns the command line arguments `args`, with the options of the config file inserted
+/// before the user's arguments, so that command line options override config file ones.
+///
+/// The config file is the one given with `--config`, or [`DEFAULT_CONFIG_FILENAME`] in the home
+/// directory if it exists.
+pub fn with_config_args(args: Vec<OsString>) -> Result<Vec<OsString>, OptionsError> {
+    let path = match config_file(&args).or_else(default_config_file) {
+        Some(path) => path,
+        None => return Ok(args),
+    };
+    let content = std::fs::read_to_string(&path).map_err(|e| {
+        OptionsError::Error(format!(
+            "Config file {} can not be read: {e}",
+            path.display()
+        ))
+    })?;
+    let config_args = parse(&content, &path)?;
+
+    let mut args = args.into_iter();
+    let mut all_args = vec![];
+    all_args.extend(args.next());
+    all_args.extend(config_args.into_iter().map(OsString::from));
+    all_args.extend(args);
+    Ok(all_args)
+}
+
+/// Returns the config file given with `--config` in the command line arguments `args`.
+fn config_file(args: &[OsString]) -> Option<PathBuf> {
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        let arg = arg.to_string_lossy();
+        if arg == "--" {
+            break;
+        }
+        if arg == "--config" {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(value) = arg.strip_prefix("--config=") {
+            return Some(PathBuf::from(value));
+        }
+    }
+    None
+}
+
+/// Returns the [`DEFAULT_CONFIG_FILENAME`] file of the user's home directory, if it exists.
+///
+/// The current directory is never searched: a config file can run commands (with `variable-cmd`)
+/// and write files, and running Hurl in a directory we don't own must not do that.
+fn default_config_file() -> Option<PathBuf> {
+    let home = if cfg!(windows) {
+        env::var_os("USERPROFILE")
+    } else {
+        env::var_os("HOME")
+    }?;
+    let path = Path::new(&home).join(DEFAULT_CONFIG_FILENAME);
+    path.is_file().then_some(path)
+}
+
+/// Parses the TOML `content` of a config file and returns the equivalent command line arguments.
+///
+/// Each key is a long option: a boolean enables a flag (`insecure = true`), a string or a number
+/// is the option value (`connect-timeout = 10`) and an array repeats the option
+/// (`variable = ["host=localhost", "port=8000"]`).
+pub fn parse(content: &str, path: &Path) -> Result<Vec<String>, OptionsError> {
+    let table = content.parse::<toml::Table>().map_err(|e| {
+        OptionsError::Error(format!(
+            "Config file {} is not valid TOML: {}",
+            path.display(),
+            e.message()
+        ))
+    })?;
+    let mut args = vec![];
+    for (name, value) in table.iter() {
+        match value {
+            toml::Value::Array(values) => {
+                for value in values {
+                    args.extend(option_arg(name, value, path)?);
+                }
+            }
+            _ => args.extend(option_arg(name, value, path)?),
+        }
+    }
+    Ok(args)
+}
+
+/// Returns the command line argument of the option `name` with a single `value`.
+fn option_arg(
+    name: &str,
+    value: &toml::Value,
+    path: &Path,
+) -> Result<Option<String>, OptionsError> {
+    let value = match value {
+        _ if name == "config" => None,
+        toml::Value::Boolean(true) => return Ok(Some(format!("--{name}"))),
+        toml::Value::Boolean(false) => return Ok(None),
+        toml::Value::String(value) => Some(value.clone()),
+        toml::Value::Integer(value) => Some(value.to_string()),
+        toml::Value::Float(value) => Some(value.to_string()),
+        toml::Value::Datetime(_) | toml::Value::Array(_) | toml::Value::Table(_) => None,
+    };
+    let Some(value) = value else {
+        return Err(OptionsError::Error(format!(
+            "Invalid option <{name}> in config file {}",
+            path.display()
+        )));
+    };
+    // The value is attached with `=`, so that a value starting with `-` is not taken as an option.
+    Ok(Some(format!("--{name}={value}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = r#"
+# Default options
+insecure = true
+location = false
+connect-timeout = 10
+proxy = "localhost:3128"
+user-agent = "Hurl Agent"
+variable = ["host=localhost", "port=8000"]
+"#;
+        assert_eq!(
+            parse(content, Path::new(".hurl.toml")).unwrap(),
+            vec![
+                "--connect-timeout=10",
+                "--insecure",
+                "--proxy=localhost:3128",
+                "--user-agent=Hurl Agent",
+                "--variable=host=localhost",
+                "--variable=port=8000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse("config = \"other.toml\"", Path::new(".hurl.toml"))
+                .err()
+                .unwrap(),
+            OptionsError::Error("Invalid option <config> in config file .hurl.toml".to_string())
+        );
+        assert_eq!(
+            parse("[proxy]\nhost = \"localhost\"", Path::new(".hurl.toml"))
+                .err()
+                .unwrap(),
+            OptionsError::Error("Invalid option <proxy> in config file .hurl.toml".to_string())
+        );
+        assert_eq!(
+            parse("variable = [[\"a=1\"]]", Path::new(".hurl.toml"))
+                .err()
+                .unwrap(),
+            OptionsError::Error("Invalid option <variable> in config file .hurl.toml".to_string())
+        );
+        assert!(parse("insecure", Path::new(".hurl.toml")).is_err());
+    }
+
+    #[test]
+    fn test_config_file() {
+        let args = |args: &[&str]| args.iter().map(OsString::from).collect::<Vec<_>>();
+        assert_eq!(config_file(&args(&["hurl", "foo.hurl"])), None);
+        assert_eq!(
+            config_file(&args(&["hurl", "--config", "ci.toml", "foo.hurl"])),
+            Some(PathBuf::from("ci.toml"))
+        );
+        assert_eq!(
+            config_file(&args(&["hurl", "--config=ci.toml", "foo.hurl"])),
+            Some(PathBuf::from("ci.toml"))
+        );
+    }
+
+    #[test]
+    fn test_with_config_args() {
+        let path = std::env::temp_dir().join("hurl_test_with_config_args.toml");
+        std::fs::write(&path, "insecure = true\nmax-time = 10\n").unwrap();
+        let args = vec![
+            OsString::from("hurl"),
+            OsString::from("--config"),
+            path.clone().into_os_string(),
+            OsString::from("--max-time"),
+            OsString::from("20"),
+            OsString::from("foo.hurl"),
+        ];
+        // Config options come first, so the options of the command line win.
+        assert_eq!(
+            with_config_args(args).unwrap(),
+            vec![
+                OsString::from("hurl"),
+                OsString::from("--insecure"),
+                OsString::from("--max-time=10"),
+                OsString::from("--config"),
+                path.clone().into_os_string(),
+                OsString::from("--max-time"),
+                OsString::from("20"),
+                OsString::from("foo.hurl"),
+            ]
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+}
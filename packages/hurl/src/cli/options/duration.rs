@@ -0,0 +1,90 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use std::time::Duration;
+
+use hurl_core::ast::DurationUnit;
+use hurl_core::error::Error;
+use hurl_core::parser;
+
+/// Parses a command line duration `s`, where the default unit is seconds.
+pub fn parse_seconds(s: &str) -> Result<Duration, String> {
+    parse(s, DurationUnit::Second)
+}
+
+/// Parses a command line duration `s`, where the default unit is milliseconds.
+pub fn parse_milliseconds(s: &str) -> Result<Duration, String> {
+    parse(s, DurationUnit::MilliSecond)
+}
+
+/// Parses a duration `s` (like `500ms`, `2s` or `1m`), using `default_unit` when no unit is given.
+///
+/// The error message is the one of the `[Options]` section durations.
+fn parse(s: &str, default_unit: DurationUnit) -> Result<Duration, String> {
+    match parser::parse_duration(s) {
+        Ok(duration) => Ok(duration.to_std(default_unit)),
+        Err(error) => Err(format!("invalid duration <{s}>, {}", error.fixme())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("10").unwrap(), Duration::from_secs(10));
+        assert_eq!(parse_seconds("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_seconds("10s").unwrap(), Duration::from_secs(10));
+        assert_eq!(parse_seconds("2m").unwrap(), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn test_parse_milliseconds() {
+        assert_eq!(parse_milliseconds("10").unwrap(), Duration::from_millis(10));
+        assert_eq!(
+            parse_milliseconds("500ms").unwrap(),
+            Duration::from_millis(500)
+        );
+        assert_eq!(parse_milliseconds("10s").unwrap(), Duration::from_secs(10));
+        assert_eq!(parse_milliseconds("2m").unwrap(), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        for s in ["", "-1", "1.5s", "10 s", "s"] {
+            let message = format!(
+                "invalid duration <{s}>, expecting a positive integer with an optional unit (ms, s or m)"
+            );
+            assert_eq!(parse_seconds(s).unwrap_err(), message);
+            assert_eq!(parse_milliseconds(s).unwrap_err(), message);
+        }
+        assert_eq!(
+            parse_seconds("10min").unwrap_err(),
+            "invalid duration <10min>, the duration unit <min> is not valid. Valid values are ms, s or m"
+        );
+        assert_eq!(
+            parse_seconds("10sec").unwrap_err(),
+            "invalid duration <10sec>, the duration unit <sec> is not valid. Valid values are ms, s or m"
+        );
+        assert_eq!(
+            parse_milliseconds("307445734561825861m").unwrap_err(),
+            "invalid duration <307445734561825861m>, the duration is too large"
+        );
+    }
+}
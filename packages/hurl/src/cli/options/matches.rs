@@ -15,16 +15,16 @@
  * limitations under the License.
  *
  */
-use super::variables::{parse as parse_variable, parse_value};
+use super::variables::{parse as parse_variable, parse_command as parse_variable_command};
 use super::OptionsError;
 use crate::cli::options::ErrorFormat;
 use crate::cli::OutputType;
 use clap::ArgMatches;
 use hurl::runner::Value;
 use hurl_core::ast::Retry;
+use percent_encoding::AsciiSet;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader, IsTerminal};
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{env, io};
@@ -99,8 +99,7 @@ pub fn compressed(arg_matches: &ArgMatches) -> bool {
 }
 
 pub fn connect_timeout(arg_matches: &ArgMatches) -> Duration {
-    let value = get::<u64>(arg_matches, "connect_timeout").unwrap();
-    Duration::from_secs(value)
+    get::<Duration>(arg_matches, "connect_timeout").unwrap()
 }
 
 pub fn connects_to(arg_matches: &ArgMatches) -> Vec<String> {
@@ -123,9 +122,38 @@ pub fn cookie_output_file(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "cookies_output_file")
 }
 
+pub fn dump_response_file(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "dump_response")
+}
+
+/// Returns the urlencoded body built from the `--data-urlencode` options, as curl does.
+///
+/// Each value is either `content`, `=content` or `name=content`: only the content is encoded,
+/// and the fields are joined with `&`. Like curl, only unreserved characters are kept as is, so a
+/// space is encoded as `%20` (and not `+`).
+pub fn data_urlencode(arg_matches: &ArgMatches) -> Option<String> {
+    const UNRESERVED: &AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+        .remove(b'-')
+        .remove(b'.')
+        .remove(b'_')
+        .remove(b'~');
+    let values = get_strings(arg_matches, "data_urlencode")?;
+    let fields = values
+        .iter()
+        .map(|value| {
+            let encode = |s: &str| percent_encoding::utf8_percent_encode(s, UNRESERVED).to_string();
+            match value.split_once('=') {
+                Some(("", content)) => encode(content),
+                Some((name, content)) => format!("{name}={}", encode(content)),
+                None => encode(value),
+            }
+        })
+        .collect::<Vec<_>>();
+    Some(fields.join("&"))
+}
+
 pub fn delay(arg_matches: &ArgMatches) -> Duration {
-    let millis = get::<u64>(arg_matches, "delay").unwrap();
-    Duration::from_millis(millis)
+    get::<Duration>(arg_matches, "delay").unwrap()
 }
 
 pub fn error_format(arg_matches: &ArgMatches) -> ErrorFormat {
@@ -214,6 +242,10 @@ pub fn junit_file(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "junit")
 }
 
+pub fn markdown_file(arg_matches: &ArgMatches) -> Option<String> {
+    get::<String>(arg_matches, "markdown")
+}
+
 pub fn max_redirect(arg_matches: &ArgMatches) -> Option<usize> {
     match get::<i32>(arg_matches, "max_redirects").unwrap() {
         m if m == -1 => None,
@@ -229,6 +261,30 @@ pub fn output(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "output")
 }
 
+pub fn output_dir(arg_matches: &ArgMatches) -> Result<Option<PathBuf>, OptionsError> {
+    if let Some(dir) = get::<String>(arg_matches, "output_dir") {
+        let path = Path::new(&dir);
+        if !path.exists() {
+            match std::fs::create_dir(path) {
+                Err(_) => Err(OptionsError::Error(format!(
+                    "Output dir {} can not be created",
+                    path.display()
+                ))),
+                Ok(_) => Ok(Some(path.to_path_buf())),
+            }
+        } else if path.is_dir() {
+            Ok(Some(path.to_path_buf()))
+        } else {
+            Err(OptionsError::Error(format!(
+                "{} is not a valid directory",
+                path.display()
+            )))
+        }
+    } else {
+        Ok(None)
+    }
+}
+
 pub fn output_type(arg_matches: &ArgMatches) -> OutputType {
     if has_flag(arg_matches, "json") {
         OutputType::Json
@@ -256,6 +312,31 @@ pub fn proxy(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "proxy")
 }
 
+pub fn proxy_cacert_file(arg_matches: &ArgMatches) -> Result<Option<String>, OptionsError> {
+    match get_string(arg_matches, "proxy_cacert_file") {
+        None => Ok(None),
+        Some(filename) => {
+            let path = Path::new(&filename);
+            if path.exists() {
+                Ok(Some(filename))
+            } else {
+                Err(OptionsError::Error(format!(
+                    "input file {} does not exist",
+                    path.display()
+                )))
+            }
+        }
+    }
+}
+
+pub fn reload_ca_store(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "reload_ca_store")
+}
+
+pub fn reload_variables(arg_matches: &ArgMatches) -> bool {
+    has_flag(arg_matches, "reload_variables")
+}
+
 pub fn resolves(arg_matches: &ArgMatches) -> Vec<String> {
     get_strings(arg_matches, "resolve").unwrap_or_default()
 }
@@ -269,8 +350,11 @@ pub fn retry(arg_matches: &ArgMatches) -> Retry {
 }
 
 pub fn retry_interval(arg_matches: &ArgMatches) -> Duration {
-    let value = get::<u64>(arg_matches, "retry_interval").unwrap();
-    Duration::from_millis(value)
+    get::<Duration>(arg_matches, "retry_interval").unwrap()
+}
+
+pub fn slow_threshold(arg_matches: &ArgMatches) -> Option<Duration> {
+    get::<Duration>(arg_matches, "slow_threshold")
 }
 
 pub fn ssl_no_revoke(arg_matches: &ArgMatches) -> bool {
@@ -286,8 +370,7 @@ pub fn test(arg_matches: &ArgMatches) -> bool {
 }
 
 pub fn timeout(arg_matches: &ArgMatches) -> Duration {
-    let value = get::<u64>(arg_matches, "max_time").unwrap();
-    Duration::from_secs(value)
+    get::<Duration>(arg_matches, "max_time").unwrap()
 }
 
 pub fn to_entry(arg_matches: &ArgMatches) -> Option<usize> {
@@ -302,51 +385,23 @@ pub fn user_agent(arg_matches: &ArgMatches) -> Option<String> {
     get::<String>(arg_matches, "user_agent")
 }
 
-/// Returns a map of variables from the command line options `matches`.
-pub fn variables(matches: &ArgMatches) -> Result<HashMap<String, Value>, OptionsError> {
+/// Returns the variables files from the command line options `matches`.
+pub fn variables_files(matches: &ArgMatches) -> Vec<String> {
+    get_strings(matches, "variables_file").unwrap_or_default()
+}
+
+/// Returns the variables that override variables files: `--variable-cmd` variables (whose commands
+/// are run here) then inline `--variable` variables.
+pub fn variables_overrides(matches: &ArgMatches) -> Result<HashMap<String, Value>, OptionsError> {
     let mut variables = HashMap::new();
 
-    // Use environment variables prefix by HURL_
-    for (env_name, env_value) in env::vars() {
-        if let Some(name) = env_name.strip_prefix("HURL_") {
-            let value = parse_value(env_value.as_str())?;
+    if let Some(input) = get_strings(matches, "variable_cmd") {
+        for s in input {
+            let (name, value) = parse_variable_command(&s)?;
             variables.insert(name.to_string(), value);
         }
     }
 
-    if let Some(filenames) = get_strings(matches, "variables_file") {
-        for f in filenames.iter() {
-            let path = Path::new(&f);
-            if !path.exists() {
-                return Err(OptionsError::Error(format!(
-                    "Properties file {} does not exist",
-                    path.display()
-                )));
-            }
-
-            let file = File::open(path).unwrap();
-            let reader = BufReader::new(file);
-            for (index, line) in reader.lines().enumerate() {
-                let line = match line {
-                    Ok(s) => s,
-                    Err(_) => {
-                        return Err(OptionsError::Error(format!(
-                            "Can not parse line {} of {}",
-                            index + 1,
-                            path.display()
-                        )))
-                    }
-                };
-                let line = line.trim();
-                if line.starts_with('#') || line.is_empty() {
-                    continue;
-                }
-                let (name, value) = parse_variable(line)?;
-                variables.insert(name.to_string(), value);
-            }
-        }
-    }
-
     if let Some(input) = get_strings(matches, "variable") {
         for s in input {
             let (name, value) = parse_variable(&s)?;
@@ -429,3 +484,55 @@ pub fn get_strings(matches: &ArgMatches, name: &str) -> Option<Vec<String>> {
 fn is_ci() -> bool {
     env::var("CI").is_ok() || env::var("TF_BUILD").is_ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_urlencode() {
+        let args = vec![
+            "hurl",
+            "--data-urlencode",
+            "name=Bob Smith",
+            "--data-urlencode",
+            "=a&b=c",
+            "--data-urlencode",
+            "café~1.0_x-y+z",
+        ];
+        let arg_matches = super::super::command().try_get_matches_from(args).unwrap();
+        assert_eq!(
+            data_urlencode(&arg_matches).unwrap(),
+            "name=Bob%20Smith&a%26b%3Dc&caf%C3%A9~1.0_x-y%2Bz"
+        );
+
+        let arg_matches = super::super::command()
+            .try_get_matches_from(vec!["hurl"])
+            .unwrap();
+        assert_eq!(data_urlencode(&arg_matches), None);
+    }
+
+    #[test]
+    fn test_slow_threshold() {
+        for (value, threshold) in [
+            ("250", Duration::from_millis(250)),
+            ("250ms", Duration::from_millis(250)),
+            ("2s", Duration::from_secs(2)),
+            ("1m", Duration::from_secs(60)),
+        ] {
+            let arg_matches = super::super::command()
+                .try_get_matches_from(vec!["hurl", "--slow-threshold", value])
+                .unwrap();
+            assert_eq!(slow_threshold(&arg_matches), Some(threshold));
+        }
+
+        let arg_matches = super::super::command()
+            .try_get_matches_from(vec!["hurl"])
+            .unwrap();
+        assert_eq!(slow_threshold(&arg_matches), None);
+
+        assert!(super::super::command()
+            .try_get_matches_from(vec!["hurl", "--slow-threshold", "2sec"])
+            .is_err());
+    }
+}
@@ -16,6 +16,8 @@
  *
  */
 mod commands;
+mod config;
+mod duration;
 mod matches;
 mod variables;
 
@@ -47,7 +49,9 @@ pub struct Options {
     pub continue_on_error: bool,
     pub cookie_input_file: Option<String>,
     pub cookie_output_file: Option<String>,
+    pub data_urlencode: Option<String>,
     pub delay: Duration,
+    pub dump_response_file: Option<String>,
     pub error_format: ErrorFormat,
     pub file_root: Option<String>,
     pub follow_location: bool,
@@ -58,16 +62,22 @@ pub struct Options {
     pub insecure: bool,
     pub interactive: bool,
     pub junit_file: Option<String>,
+    pub markdown_file: Option<String>,
     pub max_redirect: Option<usize>,
     pub no_proxy: Option<String>,
     pub output: Option<String>,
+    pub output_dir: Option<PathBuf>,
     pub output_type: OutputType,
     pub path_as_is: bool,
     pub progress_bar: bool,
     pub proxy: Option<String>,
+    pub proxy_cacert_file: Option<String>,
+    pub reload_ca_store: bool,
+    pub reload_variables: bool,
     pub resolves: Vec<String>,
     pub retry: Retry,
     pub retry_interval: Duration,
+    pub slow_threshold: Option<Duration>,
     pub ssl_no_revoke: bool,
     pub tap_file: Option<String>,
     pub test: bool,
@@ -76,6 +86,8 @@ pub struct Options {
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub variables: HashMap<String, Value>,
+    pub variables_files: Vec<String>,
+    pub variables_overrides: HashMap<String, Value>,
     pub verbose: bool,
     pub very_verbose: bool,
 }
@@ -123,22 +135,56 @@ fn get_version() -> String {
 }
 
 pub fn parse() -> Result<Options, OptionsError> {
-    let mut command = clap::Command::new("hurl")
+    let mut command = command();
+    let args = config::with_config_args(env::args_os().collect())?;
+    let arg_matches = command.try_get_matches_from_mut(args)?;
+    let opts = parse_matches(&arg_matches)?;
+
+    // If we've no file input (either from the standard input or from the command line arguments),
+    // we just print help and exit.
+    if opts.input_files.is_empty() && io::stdin().is_terminal() {
+        let help = command.render_help().to_string();
+        return Err(OptionsError::Error(help));
+    }
+
+    if opts.cookie_output_file.is_some() && opts.input_files.len() > 1 {
+        return Err(OptionsError::Error(
+            "Only save cookies for a unique session".to_string(),
+        ));
+    }
+    Ok(opts)
+}
+
+/// Computes again the variables of `opts`, with the same precedence as [`parse`]. This is used by
+/// `--reload-variables` to take into account variables files modified during a run: only variables
+/// files are read again, `--variable-cmd` commands are not run again.
+pub fn reload_variables(opts: &Options) -> Result<HashMap<String, Value>, OptionsError> {
+    variables::merge(&opts.variables_files, &opts.variables_overrides)
+}
+
+fn command() -> clap::Command {
+    clap::Command::new("hurl")
         .version(get_version())
         .disable_colored_help(true)
         .about("Run Hurl file(s) or standard input")
+        // An option can be set multiple times, the last one wins: options of the command line
+        // override options of the config file.
+        .args_override_self(true)
         .arg(commands::aws_sigv4())
         .arg(commands::cacert_file())
         .arg(commands::client_cert_file())
         .arg(commands::client_key_file())
         .arg(commands::color())
         .arg(commands::compressed())
+        .arg(commands::config_file())
         .arg(commands::connect_timeout())
         .arg(commands::connect_to())
         .arg(commands::continue_on_error())
         .arg(commands::cookies_input_file())
         .arg(commands::cookies_output_file())
+        .arg(commands::data_urlencode())
         .arg(commands::delay())
+        .arg(commands::dump_response())
         .arg(commands::error_format())
         .arg(commands::fail_at_end())
         .arg(commands::file_root())
@@ -156,40 +202,30 @@ pub fn parse() -> Result<Options, OptionsError> {
         .arg(commands::no_output())
         .arg(commands::noproxy())
         .arg(commands::output())
+        .arg(commands::output_dir())
         .arg(commands::path_as_is())
         .arg(commands::proxy())
+        .arg(commands::proxy_cacert_file())
+        .arg(commands::reload_ca_store())
         .arg(commands::report_html())
         .arg(commands::report_junit())
+        .arg(commands::report_markdown())
         .arg(commands::report_tap())
+        .arg(commands::reload_variables())
         .arg(commands::resolve())
         .arg(commands::retry())
         .arg(commands::retry_interval())
+        .arg(commands::slow_threshold())
         .arg(commands::ssl_no_revoke())
         .arg(commands::test())
         .arg(commands::to_entry())
         .arg(commands::user_agent())
         .arg(commands::user())
         .arg(commands::variable())
+        .arg(commands::variable_cmd())
         .arg(commands::variables_file())
         .arg(commands::verbose())
-        .arg(commands::very_verbose());
-
-    let arg_matches = command.try_get_matches_from_mut(env::args_os())?;
-    let opts = parse_matches(&arg_matches)?;
-
-    // If we've no file input (either from the standard input or from the command line arguments),
-    // we just print help and exit.
-    if opts.input_files.is_empty() && io::stdin().is_terminal() {
-        let help = command.render_help().to_string();
-        return Err(OptionsError::Error(help));
-    }
-
-    if opts.cookie_output_file.is_some() && opts.input_files.len() > 1 {
-        return Err(OptionsError::Error(
-            "Only save cookies for a unique session".to_string(),
-        ));
-    }
-    Ok(opts)
+        .arg(commands::very_verbose())
 }
 
 fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
@@ -204,7 +240,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let continue_on_error = matches::continue_on_error(arg_matches);
     let cookie_input_file = matches::cookie_input_file(arg_matches);
     let cookie_output_file = matches::cookie_output_file(arg_matches);
+    let data_urlencode = matches::data_urlencode(arg_matches);
     let delay = matches::delay(arg_matches);
+    let dump_response_file = matches::dump_response_file(arg_matches);
     let error_format = matches::error_format(arg_matches);
     let file_root = matches::file_root(arg_matches);
     let follow_location = matches::follow_location(arg_matches);
@@ -215,16 +253,22 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let insecure = matches::insecure(arg_matches);
     let interactive = matches::interactive(arg_matches);
     let junit_file = matches::junit_file(arg_matches);
+    let markdown_file = matches::markdown_file(arg_matches);
     let max_redirect = matches::max_redirect(arg_matches);
     let no_proxy = matches::no_proxy(arg_matches);
     let progress_bar = matches::progress_bar(arg_matches);
     let path_as_is = matches::path_as_is(arg_matches);
     let proxy = matches::proxy(arg_matches);
+    let proxy_cacert_file = matches::proxy_cacert_file(arg_matches)?;
     let output = matches::output(arg_matches);
+    let output_dir = matches::output_dir(arg_matches)?;
     let output_type = matches::output_type(arg_matches);
+    let reload_ca_store = matches::reload_ca_store(arg_matches);
+    let reload_variables = matches::reload_variables(arg_matches);
     let resolves = matches::resolves(arg_matches);
     let retry = matches::retry(arg_matches);
     let retry_interval = matches::retry_interval(arg_matches);
+    let slow_threshold = matches::slow_threshold(arg_matches);
     let ssl_no_revoke = matches::ssl_no_revoke(arg_matches);
     let tap_file = matches::tap_file(arg_matches);
     let test = matches::test(arg_matches);
@@ -232,7 +276,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
     let to_entry = matches::to_entry(arg_matches);
     let user = matches::user(arg_matches);
     let user_agent = matches::user_agent(arg_matches);
-    let variables = matches::variables(arg_matches)?;
+    let variables_files = matches::variables_files(arg_matches);
+    let variables_overrides = matches::variables_overrides(arg_matches)?;
+    let variables = variables::merge(&variables_files, &variables_overrides)?;
     let verbose = matches::verbose(arg_matches);
     let very_verbose = matches::very_verbose(arg_matches);
     Ok(Options {
@@ -247,7 +293,9 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
         continue_on_error,
         cookie_input_file,
         cookie_output_file,
+        data_urlencode,
         delay,
+        dump_response_file,
         error_format,
         file_root,
         follow_location,
@@ -258,16 +306,22 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
         insecure,
         interactive,
         junit_file,
+        markdown_file,
         max_redirect,
         no_proxy,
         path_as_is,
         progress_bar,
         proxy,
+        proxy_cacert_file,
         output,
+        output_dir,
         output_type,
+        reload_ca_store,
+        reload_variables,
         resolves,
         retry,
         retry_interval,
+        slow_threshold,
         ssl_no_revoke,
         tap_file,
         test,
@@ -276,6 +330,8 @@ fn parse_matches(arg_matches: &ArgMatches) -> Result<Options, OptionsError> {
         user,
         user_agent,
         variables,
+        variables_files,
+        variables_overrides,
         verbose,
         very_verbose,
     })
@@ -300,6 +356,8 @@ impl Options {
         let max_redirect = self.max_redirect;
         let path_as_is = self.path_as_is;
         let proxy = self.proxy.clone();
+        let proxy_cacert_file = self.proxy_cacert_file.clone();
+        let reload_ca_store = self.reload_ca_store;
         let no_proxy = self.no_proxy.clone();
         let cookie_input_file = self.cookie_input_file.clone();
         let timeout = self.timeout;
@@ -308,6 +366,7 @@ impl Options {
         let user_agent = self.user_agent.clone();
         let compressed = self.compressed;
         let continue_on_error = self.continue_on_error;
+        let data_urlencode = self.data_urlencode.clone();
         let delay = self.delay;
         let file_root = match self.file_root {
             Some(ref filename) => Path::new(filename),
@@ -335,6 +394,7 @@ impl Options {
         let resolves = self.resolves.clone();
         let retry = self.retry;
         let retry_interval = self.retry_interval;
+        let slow_threshold = self.slow_threshold;
         let ignore_asserts = self.ignore_asserts;
         let ssl_no_revoke = self.ssl_no_revoke;
 
@@ -350,6 +410,7 @@ impl Options {
             .continue_on_error(continue_on_error)
             .context_dir(&context_dir)
             .cookie_input_file(cookie_input_file)
+            .data_urlencode(data_urlencode)
             .follow_location(follow_location)
             .ignore_asserts(ignore_asserts)
             .insecure(insecure)
@@ -359,9 +420,12 @@ impl Options {
             .post_entry(post_entry)
             .pre_entry(pre_entry)
             .proxy(proxy)
+            .proxy_cacert_file(proxy_cacert_file)
+            .reload_ca_store(reload_ca_store)
             .resolves(&resolves)
             .retry(retry)
             .retry_interval(retry_interval)
+            .slow_threshold(slow_threshold)
             .ssl_no_revoke(ssl_no_revoke)
             .timeout(timeout)
             .to_entry(to_entry)
@@ -382,3 +446,76 @@ impl Options {
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variables_precedence() {
+        let path = env::temp_dir().join("hurl_test_variables_precedence.properties");
+        std::fs::write(&path, "precedence_a=2\nprecedence_b=file\n").unwrap();
+        let path = path.to_str().unwrap();
+
+        // Inline variables override variables files values, whatever the order of the options.
+        for args in [
+            vec![
+                "hurl",
+                "--variable",
+                "precedence_a=1",
+                "--variables-file",
+                path,
+            ],
+            vec![
+                "hurl",
+                "--variables-file",
+                path,
+                "--variable",
+                "precedence_a=1",
+            ],
+        ] {
+            let arg_matches = command().try_get_matches_from(args).unwrap();
+            let variables = parse_matches(&arg_matches).unwrap().variables;
+            assert_eq!(variables["precedence_a"], Value::Integer(1));
+            assert_eq!(variables["precedence_b"], Value::String("file".to_string()));
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_reload_variables() {
+        let dir =
+            env::temp_dir().join(format!("hurl_test_reload_variables_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("vars.env");
+        let counter = dir.join("counter");
+        std::fs::write(&file, "token=abc\n").unwrap();
+        let file = file.to_str().unwrap();
+        let variable_cmd = format!("id=echo run >> {}; echo 42", counter.display());
+
+        let args = vec![
+            "hurl",
+            "--variables-file",
+            file,
+            "--variable-cmd",
+            &variable_cmd,
+        ];
+        let arg_matches = command().try_get_matches_from(args).unwrap();
+        let opts = parse_matches(&arg_matches).unwrap();
+        assert_eq!(opts.variables["token"], Value::String("abc".to_string()));
+        assert_eq!(opts.variables["id"], Value::String("42".to_string()));
+
+        // A modified variables file is taken into account.
+        std::fs::write(file, "token=def\nid=file\n").unwrap();
+        let variables = reload_variables(&opts).unwrap();
+        assert_eq!(variables["token"], Value::String("def".to_string()));
+        assert_eq!(variables["id"], Value::String("42".to_string()));
+
+        // The command of the variable has been run only once.
+        reload_variables(&opts).unwrap();
+        assert_eq!(std::fs::read_to_string(&counter).unwrap(), "run\n");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
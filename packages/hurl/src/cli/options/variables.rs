@@ -16,6 +16,13 @@
  *
  */
 
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::process::Command;
+
 use super::OptionsError;
 use crate::runner::Value;
 
@@ -56,6 +63,128 @@ pub fn parse_value(s: &str) -> Result<Value, OptionsError> {
     }
 }
 
+/// Parses a `name=command` string, runs the command and returns the variable with the command
+/// standard output as value.
+///
+/// The command is run by the system shell, and its trailing newline is removed. A command that
+/// fails (non-zero exit code) is an error.
+pub fn parse_command(s: &str) -> Result<(String, Value), OptionsError> {
+    let Some((name, command)) = s.split_once('=') else {
+        return Err(OptionsError::Error(format!(
+            "Missing command for variable {s}!"
+        )));
+    };
+    let output = shell_command(command).output().map_err(|e| {
+        OptionsError::Error(format!(
+            "Command <{command}> for variable {name} can not be run: {e}"
+        ))
+    })?;
+    if !output.status.success() {
+        let status = match output.status.code() {
+            Some(code) => format!("exit code {code}"),
+            None => "no exit code".to_string(),
+        };
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stderr = stderr.trim_end();
+        let message = if stderr.is_empty() {
+            format!("Command <{command}> for variable {name} failed with {status}")
+        } else {
+            format!("Command <{command}> for variable {name} failed with {status}: {stderr}")
+        };
+        return Err(OptionsError::Error(message));
+    }
+    let Ok(stdout) = String::from_utf8(output.stdout) else {
+        return Err(OptionsError::Error(format!(
+            "Command <{command}> for variable {name} has a non UTF-8 output"
+        )));
+    };
+    let value = stdout
+        .strip_suffix('\n')
+        .map(|s| s.strip_suffix('\r').unwrap_or(s))
+        .unwrap_or(&stdout);
+    Ok((name.to_string(), Value::String(value.to_string())))
+}
+
+/// Parses a properties file, returning its variables in the order of the file.
+///
+/// Empty lines and lines starting with `#` are ignored. The file is read on each call, so a
+/// modified file can be taken into account by parsing it again (see `--reload-variables`).
+pub fn parse_file(path: &Path) -> Result<Vec<(String, Value)>, OptionsError> {
+    if !path.exists() {
+        return Err(OptionsError::Error(format!(
+            "Properties file {} does not exist",
+            path.display()
+        )));
+    }
+
+    let file = File::open(path).unwrap();
+    let reader = BufReader::new(file);
+    let mut variables = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        let line = match line {
+            Ok(s) => s,
+            Err(_) => {
+                return Err(OptionsError::Error(format!(
+                    "Can not parse line {} of {}",
+                    index + 1,
+                    path.display()
+                )))
+            }
+        };
+        let line = line.trim();
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        variables.push(parse(line)?);
+    }
+    Ok(variables)
+}
+
+/// Computes the variables of a run: `HURL_` prefixed environment variables, then variables of the
+/// `files` properties files, then `overrides`, each one overriding the previous ones.
+///
+/// `overrides` are computed once (see `--variable-cmd`), while `files` are read on each call.
+pub fn merge(
+    files: &[String],
+    overrides: &HashMap<String, Value>,
+) -> Result<HashMap<String, Value>, OptionsError> {
+    let mut variables = HashMap::new();
+
+    // Use environment variables prefix by HURL_
+    for (env_name, env_value) in env::vars() {
+        if let Some(name) = env_name.strip_prefix("HURL_") {
+            let value = parse_value(env_value.as_str())?;
+            variables.insert(name.to_string(), value);
+        }
+    }
+
+    for f in files {
+        for (name, value) in parse_file(Path::new(f))? {
+            variables.insert(name, value);
+        }
+    }
+
+    for (name, value) in overrides {
+        variables.insert(name.clone(), value.clone());
+    }
+
+    Ok(variables)
+}
+
+#[cfg(target_family = "unix")]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c").arg(command);
+    cmd
+}
+
+#[cfg(target_family = "windows")]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.arg("/C").arg(command);
+    cmd
+}
+
 #[cfg(test)]
 mod tests {
     use super::OptionsError;
@@ -97,6 +226,30 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_parse_command() {
+        assert_eq!(
+            parse_command("token=echo s3cr3t").unwrap(),
+            ("token".to_string(), Value::String("s3cr3t".to_string()))
+        );
+        // Only the trailing newline is removed, and the value is always a string.
+        assert_eq!(
+            parse_command("id=printf ' 42 \\n\\n'").unwrap(),
+            ("id".to_string(), Value::String(" 42 \n".to_string()))
+        );
+        assert_eq!(
+            parse_command("token=exit 3").err().unwrap(),
+            OptionsError::Error(
+                "Command <exit 3> for variable token failed with exit code 3".to_string()
+            )
+        );
+        assert_eq!(
+            parse_command("token").err().unwrap(),
+            OptionsError::Error("Missing command for variable token!".to_string())
+        );
+    }
+
     #[test]
     fn test_parse_value() {
         assert_eq!(
@@ -28,6 +28,20 @@ pub struct Certificate {
     pub start_date: DateTime<Utc>,
     pub expire_date: DateTime<Utc>,
     pub serial_number: String,
+    /// The number of certificates in the certificates chain sent by the server
+    pub chain_length: usize,
+    /// True if the certificate status has been requested and successfully verified with OCSP
+    /// stapling (see `verify-status` option)
+    pub ocsp_verified: bool,
+    /// The certificate in PEM format, only available with TLS backends exposing it
+    pub pem: Option<String>,
+}
+
+impl Certificate {
+    /// Returns true if this certificate is self-signed (its subject is also its issuer).
+    pub fn is_self_signed(&self) -> bool {
+        self.subject == self.issuer
+    }
 }
 
 impl TryFrom<CertInfo> for Certificate {
@@ -50,6 +64,9 @@ impl TryFrom<CertInfo> for Certificate {
             start_date,
             expire_date,
             serial_number,
+            chain_length: cert_info.chain_length,
+            ocsp_verified: false,
+            pem: attributes.get("cert").cloned(),
         })
     }
 }
@@ -203,6 +220,23 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_is_self_signed() {
+        let mut certificate = Certificate {
+            subject: "C = US, CN = localhost".to_string(),
+            issuer: "C = US, CN = localhost".to_string(),
+            start_date: Default::default(),
+            expire_date: Default::default(),
+            serial_number: String::new(),
+            chain_length: 1,
+            ocsp_verified: false,
+            pem: None,
+        };
+        assert!(certificate.is_self_signed());
+        certificate.issuer = "C = US, O = Let's Encrypt, CN = R3".to_string();
+        assert!(!certificate.is_self_signed());
+    }
+
     #[test]
     fn test_try_from() {
         assert_eq!(
@@ -215,7 +249,10 @@ mod tests {
                     "Serial Number:1ee8b17f1b64d8d6b3de870103d2a4f533535ab0".to_string(),
                     "Start date:Jan 10 08:29:52 2023 GMT".to_string(),
                     "Expire date:Oct 30 08:29:52 2025 GMT".to_string(),
-                ]
+                    "Cert:-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n"
+                        .to_string(),
+                ],
+                chain_length: 1,
             })
             .unwrap(),
             Certificate {
@@ -229,13 +266,21 @@ mod tests {
                     .unwrap()
                     .with_timezone(&chrono::Utc),
                 serial_number: "1e:e8:b1:7f:1b:64:d8:d6:b3:de:87:01:03:d2:a4:f5:33:53:5a:b0"
-                    .to_string()
+                    .to_string(),
+                chain_length: 1,
+                ocsp_verified: false,
+                pem: Some(
+                    "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n".to_string()
+                ),
             }
         );
         assert_eq!(
-            Certificate::try_from(CertInfo { data: vec![] })
-                .err()
-                .unwrap(),
+            Certificate::try_from(CertInfo {
+                data: vec![],
+                chain_length: 0
+            })
+            .err()
+            .unwrap(),
             "missing Subject attribute in {}".to_string()
         );
     }
@@ -15,8 +15,10 @@
  * limitations under the License.
  *
  */
+use std::cell::Cell;
 use std::str;
 use std::str::FromStr;
+use std::time::Duration;
 
 use base64::engine::general_purpose;
 use base64::Engine;
@@ -34,7 +36,8 @@ use crate::http::request::*;
 use crate::http::request_spec::*;
 use crate::http::response::*;
 use crate::http::timings::Timings;
-use crate::http::{easy_ext, Call, Header, HttpError, Verbosity};
+use crate::http::transfer::{parse_excess, Transfer};
+use crate::http::{easy_ext, websocket, Call, Header, HttpError, Verbosity};
 use crate::util::logger::Logger;
 use crate::util::path::ContextDir;
 
@@ -82,11 +85,24 @@ impl Client {
         // libcurl returns a single list of headers for the 2 responses
         // Hurl needs to keep everything.
         let mut redirect_count = 0;
+        // The requests of the chain, to detect redirect loops, with the cookies of the cookie
+        // storage before each request.
+        let mut visited = vec![];
+        let mut cookies = if options.follow_location {
+            self.get_cookie_storage()
+        } else {
+            vec![]
+        };
         loop {
             let call = self.execute(&request_spec, options, logger)?;
             let base_url = call.request.base_url()?;
             let redirect_url = self.get_follow_location(&call.response, &base_url);
             let status = call.response.status;
+            visited.push(Visit {
+                method: call.request.method.clone(),
+                url: call.request.url.clone(),
+                cookies,
+            });
             calls.push(call);
             if !options.follow_location || redirect_url.is_none() {
                 break;
@@ -96,18 +112,35 @@ impl Client {
             logger.debug(format!("=> Redirect to {redirect_url}").as_str());
             logger.debug("");
             redirect_count += 1;
+            let redirect_method = get_redirect_method(status, request_spec.method);
+            cookies = self.get_cookie_storage();
+            if let Some(urls) = redirect_loop(&visited, &redirect_method, &redirect_url, &cookies) {
+                return Err(HttpError::RedirectLoop { urls });
+            }
             if let Some(max_redirect) = options.max_redirect {
                 if redirect_count > max_redirect {
                     return Err(HttpError::TooManyRedirect);
                 }
             }
-            let redirect_method = get_redirect_method(status, request_spec.method);
             request_spec = RequestSpec {
                 method: redirect_method,
                 url: redirect_url,
                 ..Default::default()
             };
         }
+        // The last response keeps track of all the redirections that have been followed.
+        let redirects = calls
+            .iter()
+            .take(calls.len() - 1)
+            .map(|call| Redirect {
+                url: call.response.url.clone(),
+                status: call.response.status,
+                headers: call.response.headers.clone(),
+            })
+            .collect::<Vec<_>>();
+        if let Some(call) = calls.last_mut() {
+            call.response.redirects = redirects;
+        }
         Ok(calls)
     }
 
@@ -155,6 +188,9 @@ impl Client {
         if let Some(proxy) = options.proxy.clone() {
             self.handle.proxy(proxy.as_str())?;
         }
+        if let Some(proxy_cacert_file) = options.proxy_cacert_file.clone() {
+            self.handle.proxy_cainfo(&proxy_cacert_file)?;
+        }
         if let Some(s) = options.no_proxy.clone() {
             self.handle.noproxy(s.as_str())?;
         }
@@ -162,6 +198,12 @@ impl Client {
         self.handle.connect_timeout(options.connect_timeout)?;
 
         self.set_ssl_options(options.ssl_no_revoke)?;
+        if options.reload_ca_store {
+            easy_ext::ca_cache_timeout(&mut self.handle, Duration::ZERO)?;
+        }
+        if options.verify_status {
+            easy_ext::ssl_verify_status(&mut self.handle, true)?;
+        }
 
         let url = self.generate_url(&request_spec.url, &request_spec.querystring);
         self.handle.url(url.as_str())?;
@@ -202,11 +244,16 @@ impl Client {
         // a [FormParam] section, `request_body` is empty whereas libcurl sent a url-form encoded list
         // of key-value.
         let mut request_body = Vec::<u8>::new();
+        let mut request_line = String::new();
         let mut response_body = Vec::<u8>::new();
 
         if *method == Method("HEAD".to_string()) {
             self.handle.nobody(true)?;
         }
+        let upgraded = Cell::new(false);
+        let size_excess = Cell::new(0);
+        let tls_session_offered = Cell::new(false);
+        let tls_certificate_received = Cell::new(false);
         {
             let mut transfer = self.handle.transfer();
 
@@ -215,6 +262,8 @@ impl Client {
                 easy::InfoType::HeaderOut => {
                     let mut lines = split_lines(data);
                     logger.debug_method_version_out(&lines[0]);
+                    // With a proxy tunnel, the last request line is the one sent to the server.
+                    request_line = lines[0].clone();
 
                     // Extracts request headers from libcurl debug info.
                     lines.pop().unwrap(); // Remove last empty line.
@@ -275,10 +324,21 @@ impl Client {
                 // Curl debug logs
                 easy::InfoType::Text => {
                     let len = data.len();
-                    if very_verbose && len > 0 {
+                    if len > 0 {
                         let text = str::from_utf8(&data[..len - 1]);
                         if let Ok(text) = text {
-                            logger.debug_curl(text);
+                            if let Some(excess) = parse_excess(text) {
+                                size_excess.set(size_excess.get() + excess);
+                            }
+                            if is_tls_session_offered(text) {
+                                tls_session_offered.set(true);
+                            }
+                            if is_tls_certificate_received(text) {
+                                tls_certificate_received.set(true);
+                            }
+                            if very_verbose {
+                                logger.debug_curl(text);
+                            }
                         }
                     }
                 }
@@ -289,6 +349,15 @@ impl Client {
                     if s.starts_with("HTTP/") {
                         status_lines.push(s);
                     } else {
+                        // With WebSocket, the connection is switched to another protocol after the
+                        // `101` response: we abort the transfer once its headers have been received.
+                        if options.websocket
+                            && s.trim().is_empty()
+                            && is_switching_protocols(&status_lines)
+                        {
+                            upgraded.set(true);
+                            return false;
+                        }
                         response_headers.push(s)
                     }
                 }
@@ -301,29 +370,45 @@ impl Client {
             })?;
 
             if let Err(e) = transfer.perform() {
-                let code = e.code() as i32; // due to windows build
-                let description = match e.extra_description() {
-                    None => e.description().to_string(),
-                    Some(s) => s.to_string(),
-                };
-                return Err(HttpError::Libcurl {
-                    code,
-                    description,
-                    url: url.to_string(),
-                });
+                // A transfer aborted after a WebSocket handshake is not an error.
+                if !upgraded.get() {
+                    let code = e.code() as i32; // due to windows build
+                    let description = match e.extra_description() {
+                        None => e.description().to_string(),
+                        Some(s) => s.to_string(),
+                    };
+                    return Err(HttpError::Libcurl {
+                        code,
+                        description,
+                        url: url.to_string(),
+                    });
+                }
             }
         }
 
-        let status = self.handle.response_code()?;
+        let status = if upgraded.get() {
+            101
+        } else {
+            self.handle.response_code()?
+        };
         // TODO: explain why status_lines is Vec ?
-        let version = match status_lines.last() {
+        let (version, reason) = match status_lines.last() {
             None => return Err(HttpError::StatuslineIsMissing { url }),
-            Some(status_line) => self.parse_response_version(status_line)?,
+            Some(status_line) => (
+                self.parse_response_version(status_line)?,
+                parse_response_reason(status_line),
+            ),
         };
         let headers = self.parse_response_headers(&response_headers);
         let length = response_body.len();
         let certificate = if let Some(cert_info) = easy_ext::get_certinfo(&self.handle)? {
             match Certificate::try_from(cert_info) {
+                // With status verification, libcurl fails the transfer on a missing or invalid
+                // stapled OCSP response: reaching this point means the verification succeeded.
+                Ok(value) if options.verify_status => Some(Certificate {
+                    ocsp_verified: true,
+                    ..value
+                }),
                 Ok(value) => Some(value),
                 Err(message) => {
                     logger.error(format!("can not parse certificate - {message}").as_str());
@@ -336,6 +421,18 @@ impl Client {
         let stop = Utc::now();
         let duration = (stop - start).to_std().unwrap();
         let timings = Timings::new(&mut self.handle, start, stop);
+        let transfer = Transfer::new(&mut self.handle, size_excess.get());
+        // The TLS session is resumed if the server has accepted the cached session we offered,
+        // skipping its certificate in the handshake.
+        let ssl_version = curl::Version::get()
+            .ssl_version()
+            .unwrap_or_default()
+            .to_string();
+        let tls_resumed = if is_tls_handshake_traced(&ssl_version) {
+            Some(tls_session_offered.get() && !tls_certificate_received.get())
+        } else {
+            None
+        };
         self.handle.reset();
 
         let request = Request {
@@ -347,11 +444,16 @@ impl Client {
         let response = Response {
             version,
             status,
+            reason,
             headers,
             body: response_body,
             duration,
             url,
             certificate,
+            redirects: vec![],
+            transfer,
+            tls_resumed,
+            request_line,
         };
 
         if verbose {
@@ -363,6 +465,7 @@ impl Client {
             logger.debug_important(
                 format!("Response: (received {length} bytes in {duration} ms)").as_str(),
             );
+            response.transfer.log(logger);
             logger.debug("");
 
             // FIXME: Explain why there may be multiple status line
@@ -382,6 +485,10 @@ impl Client {
             }
         }
 
+        if upgraded.get() {
+            check_websocket_accept(&request, &response)?;
+        }
+
         Ok(Call {
             request,
             response,
@@ -474,6 +581,24 @@ impl Client {
         if options.compressed && request.get_header_values("Accept-Encoding").is_empty() {
             list.append("Accept-Encoding: gzip, deflate, br")?;
         }
+        if options.websocket {
+            if request.get_header_values("Upgrade").is_empty() {
+                list.append("Upgrade: websocket")?;
+            }
+            if request.get_header_values("Connection").is_empty() {
+                list.append("Connection: Upgrade")?;
+            }
+            if request.get_header_values("Sec-WebSocket-Key").is_empty() {
+                let key = websocket::generate_key();
+                list.append(format!("Sec-WebSocket-Key: {key}").as_str())?;
+            }
+            if request
+                .get_header_values("Sec-WebSocket-Version")
+                .is_empty()
+            {
+                list.append("Sec-WebSocket-Version: 13")?;
+            }
+        }
 
         self.handle.http_headers(list)?;
         Ok(())
@@ -680,6 +805,38 @@ fn get_redirect_url(location: &str, base_url: &str) -> String {
     }
 }
 
+/// A request of a redirect chain.
+struct Visit {
+    method: String,
+    url: String,
+    /// The cookies of the cookie storage when the request has been made.
+    cookies: Vec<Cookie>,
+}
+
+/// Returns the URLs of the redirect loop if a request with `method` and `url` has already been
+/// made in the `visited` redirect chain with the same `cookies`, from the first request to `url`
+/// to the repeated one.
+///
+/// A redirection to an already visited URL is not a loop if a state has changed: a different
+/// method (like a POST followed by a GET on a 303), or a cookie set in between (like a login page
+/// redirecting to itself once its session cookie is set).
+fn redirect_loop(
+    visited: &[Visit],
+    method: &Method,
+    url: &str,
+    cookies: &[Cookie],
+) -> Option<Vec<String>> {
+    let start = visited
+        .iter()
+        .position(|v| v.method == method.0 && v.url == url && v.cookies == cookies)?;
+    let mut urls = visited[start..]
+        .iter()
+        .map(|v| v.url.clone())
+        .collect::<Vec<_>>();
+    urls.push(url.to_string());
+    Some(urls)
+}
+
 /// Returns the method used for redirecting a request/response with `response_status`.
 fn get_redirect_method(response_status: u32, original_method: Method) -> Method {
     // This replicates curl's behavior
@@ -741,6 +898,16 @@ impl Header {
     }
 }
 
+/// Returns the reason phrase of a status line, an empty string if there is none.
+fn parse_response_reason(line: &str) -> String {
+    line.trim()
+        .splitn(3, ' ')
+        .nth(2)
+        .unwrap_or("")
+        .trim()
+        .to_string()
+}
+
 /// Splits an array of bytes into HTTP lines (\r\n separator).
 fn split_lines(data: &[u8]) -> Vec<String> {
     let mut lines = vec![];
@@ -774,6 +941,66 @@ pub fn decode_header(data: &[u8]) -> Option<String> {
     }
 }
 
+/// Returns true if the last received status line is a `101 Switching Protocols` response.
+fn is_switching_protocols(status_lines: &[String]) -> bool {
+    match status_lines.last() {
+        Some(status_line) => status_line.split_whitespace().nth(1) == Some("101"),
+        None => false,
+    }
+}
+
+/// Returns true if the libcurl TLS backend `ssl_version` (like `OpenSSL/3.0.11`) logs the debug
+/// texts used to detect a TLS session resumption.
+///
+/// libcurl doesn't expose TLS session resumption: it's deduced, on a best-effort basis, from
+/// the TLS handshake messages traced by the OpenSSL backend (and the backends of OpenSSL forks),
+/// see [`is_tls_session_offered`] and [`is_tls_certificate_received`]. Other backends (Schannel,
+/// Secure Transport, GnuTLS etc...) don't trace these messages, and a TLS session resumption can't
+/// be known. A multi TLS backends libcurl lists the inactive backends between parentheses.
+fn is_tls_handshake_traced(ssl_version: &str) -> bool {
+    ["OpenSSL/", "BoringSSL", "LibreSSL/", "quictls/", "AWS-LC/"]
+        .iter()
+        .any(|backend| ssl_version.starts_with(backend))
+}
+
+/// Returns true if a libcurl debug `text` reports that a cached TLS session is offered to the server.
+fn is_tls_session_offered(text: &str) -> bool {
+    // libcurl logs `SSL reusing session ID` (or `SSL re-using session ID` for older versions).
+    text == "SSL reusing session ID" || text == "SSL re-using session ID"
+}
+
+/// Returns true if a libcurl debug `text` traces the server certificate message of a TLS handshake.
+/// A resumed TLS handshake doesn't send the server certificate again.
+fn is_tls_certificate_received(text: &str) -> bool {
+    // libcurl logs `TLSv1.2 (IN), TLS handshake, Certificate (11):`
+    text.contains("(IN), TLS handshake, Certificate (11)")
+}
+
+/// Checks that the `Sec-WebSocket-Accept` header of a WebSocket handshake `response` matches the
+/// `Sec-WebSocket-Key` header of the `request`.
+fn check_websocket_accept(request: &Request, response: &Response) -> Result<(), HttpError> {
+    let key = request
+        .headers
+        .iter()
+        .find(|h| h.name.to_lowercase() == "sec-websocket-key")
+        .map(|h| h.value.clone())
+        .unwrap_or_default();
+    let expected = websocket::accept_key(&key);
+    match response.get_header_values("Sec-WebSocket-Accept").first() {
+        Some(actual) if *actual == expected => Ok(()),
+        Some(actual) => Err(HttpError::WebSocketHandshake {
+            description: format!(
+                "invalid Sec-WebSocket-Accept header <{actual}>, expected <{expected}>"
+            ),
+            url: response.url.clone(),
+        }),
+        None => Err(HttpError::WebSocketHandshake {
+            description: "missing Sec-WebSocket-Accept header".to_string(),
+            url: response.url.clone(),
+        }),
+    }
+}
+
 /// Converts a list of [`String`] to a libcurl's list of strings.
 fn to_list(items: &[String]) -> List {
     let mut list = List::new();
@@ -798,6 +1025,17 @@ mod tests {
         assert!(Header::parse("Foo").is_none());
     }
 
+    #[test]
+    fn test_parse_response_reason() {
+        assert_eq!(
+            parse_response_reason("HTTP/1.1 404 NOT FOUND\r\n"),
+            "NOT FOUND"
+        );
+        assert_eq!(parse_response_reason("HTTP/1.0 200 OK\r\n"), "OK");
+        assert_eq!(parse_response_reason("HTTP/2 200 \r\n"), "");
+        assert_eq!(parse_response_reason("HTTP/2 200\r\n"), "");
+    }
+
     #[test]
     fn test_split_lines_header() {
         let data = b"GET /hello HTTP/1.1\r\nHost: localhost:8000\r\n\r\n";
@@ -851,6 +1089,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_redirect_loop() {
+        let get = Method("GET".to_string());
+        let visit = |method: &str, url: &str, cookies: &[Cookie]| Visit {
+            method: method.to_string(),
+            url: url.to_string(),
+            cookies: cookies.to_vec(),
+        };
+        let session = vec![Cookie {
+            domain: "localhost".to_string(),
+            include_subdomain: "FALSE".to_string(),
+            path: "/".to_string(),
+            https: "FALSE".to_string(),
+            expires: "0".to_string(),
+            name: "session".to_string(),
+            value: "1234".to_string(),
+            http_only: false,
+        }];
+        let visited = vec![
+            visit("POST", "http://localhost:8000/form", &[]),
+            visit("GET", "http://localhost:8000/a", &[]),
+            visit("GET", "http://localhost:8000/b", &[]),
+        ];
+        assert_eq!(
+            redirect_loop(&visited, &get, "http://localhost:8000/a", &[]),
+            Some(vec![
+                "http://localhost:8000/a".to_string(),
+                "http://localhost:8000/b".to_string(),
+                "http://localhost:8000/a".to_string(),
+            ])
+        );
+        assert_eq!(
+            redirect_loop(&visited, &get, "http://localhost:8000/form", &[]),
+            None
+        );
+        assert_eq!(
+            redirect_loop(&visited, &get, "http://localhost:8000/c", &[]),
+            None
+        );
+
+        // A cookie set in between changes the request.
+        assert_eq!(
+            redirect_loop(&visited, &get, "http://localhost:8000/a", &session),
+            None
+        );
+        let visited = vec![
+            visit("GET", "http://localhost:8000/a", &[]),
+            visit("GET", "http://localhost:8000/a", &session),
+        ];
+        assert_eq!(
+            redirect_loop(&visited, &get, "http://localhost:8000/a", &session),
+            Some(vec![
+                "http://localhost:8000/a".to_string(),
+                "http://localhost:8000/a".to_string(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_tls_handshake_traced() {
+        assert!(is_tls_handshake_traced("OpenSSL/3.0.11"));
+        assert!(is_tls_handshake_traced("LibreSSL/3.3.6"));
+        assert!(is_tls_handshake_traced("BoringSSL"));
+        assert!(is_tls_handshake_traced("OpenSSL/3.1.4 (Schannel)"));
+        assert!(!is_tls_handshake_traced("Schannel"));
+        assert!(!is_tls_handshake_traced("SecureTransport (OpenSSL/3.1.4)"));
+        assert!(!is_tls_handshake_traced("GnuTLS/3.7.9"));
+        assert!(!is_tls_handshake_traced(""));
+    }
+
+    #[test]
+    fn test_tls_resumption() {
+        assert!(is_tls_session_offered("SSL reusing session ID"));
+        assert!(is_tls_session_offered("SSL re-using session ID"));
+        assert!(!is_tls_session_offered(
+            "old SSL session ID is stale, removing"
+        ));
+
+        assert!(is_tls_certificate_received(
+            "TLSv1.2 (IN), TLS handshake, Certificate (11):"
+        ));
+        assert!(is_tls_certificate_received(
+            "TLSv1.3 (IN), TLS handshake, Certificate (11):"
+        ));
+        assert!(!is_tls_certificate_received(
+            "TLSv1.3 (OUT), TLS handshake, Certificate (11):"
+        ));
+        assert!(!is_tls_certificate_received(
+            "TLSv1.2 (IN), TLS handshake, Server hello (2):"
+        ));
+    }
+
     #[test]
     fn test_redirect_method() {
         // Status of the response to be redirected | method of the original request | method of the new request
@@ -33,6 +33,8 @@ pub struct ResponseCookie {
     pub attributes: Vec<CookieAttribute>,
 }
 
+/// A Set-Cookie attribute, like `Path=/` or `Secure`. Attribute names are case-insensitive:
+/// `path=/` is the same attribute as `Path=/`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CookieAttribute {
     pub name: String,
@@ -66,7 +68,7 @@ impl ResponseCookie {
     /// Returns the optional Expires attribute as `String` type.
     pub fn expires(&self) -> Option<String> {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "Expires" {
+            if attr.name.eq_ignore_ascii_case("Expires") {
                 return attr.value;
             }
         }
@@ -78,7 +80,7 @@ impl ResponseCookie {
     /// If the value is not a valid integer, the attribute is simply ignored
     pub fn max_age(&self) -> Option<i64> {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "Max-Age" {
+            if attr.name.eq_ignore_ascii_case("Max-Age") {
                 if let Some(v) = attr.value {
                     if let Ok(v2) = v.as_str().parse::<i64>() {
                         return Some(v2);
@@ -92,7 +94,7 @@ impl ResponseCookie {
     /// Returns the optional Domain attribute as `String` type.
     pub fn domain(&self) -> Option<String> {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "Domain" {
+            if attr.name.eq_ignore_ascii_case("Domain") {
                 return attr.value;
             }
         }
@@ -102,7 +104,7 @@ impl ResponseCookie {
     /// Returns the optional Path attribute as `String` type.
     pub fn path(&self) -> Option<String> {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "Path" {
+            if attr.name.eq_ignore_ascii_case("Path") {
                 return attr.value;
             }
         }
@@ -112,7 +114,7 @@ impl ResponseCookie {
     /// Return true if the Secure attribute is present.
     pub fn has_secure(&self) -> bool {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "Secure" && attr.value.is_none() {
+            if attr.name.eq_ignore_ascii_case("Secure") && attr.value.is_none() {
                 return true;
             }
         }
@@ -122,7 +124,7 @@ impl ResponseCookie {
     /// Return true if the HttpOnly attribute is present.
     pub fn has_httponly(&self) -> bool {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "HttpOnly" && attr.value.is_none() {
+            if attr.name.eq_ignore_ascii_case("HttpOnly") && attr.value.is_none() {
                 return true;
             }
         }
@@ -132,7 +134,7 @@ impl ResponseCookie {
     /// Returns the optional SameSite attribute as `String` type.
     pub fn samesite(&self) -> Option<String> {
         for attr in self.attributes.clone() {
-            if attr.name.as_str() == "SameSite" {
+            if attr.name.eq_ignore_ascii_case("SameSite") {
                 return attr.value;
             }
         }
@@ -329,6 +331,26 @@ pub mod tests {
         assert_eq!(cookie.samesite(), None);
     }
 
+    #[test]
+    fn test_attributes_case_insensitive() {
+        let cookie = ResponseCookie::parse(
+            "id=a3fWa; domain=example.org; path=/docs; expires=Wed, 21 Oct 2015 07:28:00 GMT; \
+            max-age=2592000; SECURE; httponly; samesite=Lax"
+                .to_string(),
+        )
+        .unwrap();
+        assert_eq!(cookie.domain(), Some("example.org".to_string()));
+        assert_eq!(cookie.path(), Some("/docs".to_string()));
+        assert_eq!(
+            cookie.expires(),
+            Some("Wed, 21 Oct 2015 07:28:00 GMT".to_string())
+        );
+        assert_eq!(cookie.max_age(), Some(2592000));
+        assert!(cookie.has_secure());
+        assert!(cookie.has_httponly());
+        assert_eq!(cookie.samesite(), Some("Lax".to_string()));
+    }
+
     #[test]
     fn test_trailing_semicolon() {
         assert_eq!(
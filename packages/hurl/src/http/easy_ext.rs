@@ -15,17 +15,21 @@
  * limitations under the License.
  *
  */
-use std::ffi::CStr;
+use std::ffi::{c_double, c_long, CStr};
 use std::ptr;
 use std::time::Duration;
 
 use curl::easy::Easy;
 use curl::Error;
-use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLINFO};
+use curl_sys::{curl_certinfo, curl_off_t, curl_slist, CURLoption, CURLINFO};
 
 /// Some definitions not present in curl-sys
 const CURLINFO_OFF_T: CURLINFO = 0x600000;
 
+const CURLINFO_SIZE_DOWNLOAD_T: CURLINFO = CURLINFO_OFF_T + 8;
+const CURLINFO_SPEED_DOWNLOAD_T: CURLINFO = CURLINFO_OFF_T + 9;
+const CURLINFO_SPEED_UPLOAD_T: CURLINFO = CURLINFO_OFF_T + 10;
+
 const CURLINFO_TOTAL_TIME_T: CURLINFO = CURLINFO_OFF_T + 50;
 const CURLINFO_NAMELOOKUP_TIME_T: CURLINFO = CURLINFO_OFF_T + 51;
 const CURLINFO_CONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 52;
@@ -33,11 +37,16 @@ const CURLINFO_PRETRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 53;
 const CURLINFO_STARTTRANSFER_TIME_T: CURLINFO = CURLINFO_OFF_T + 54;
 const CURLINFO_APPCONNECT_TIME_T: CURLINFO = CURLINFO_OFF_T + 56;
 
+const CURLOPT_SSL_VERIFYSTATUS: CURLoption = curl_sys::CURLOPTTYPE_LONG + 232;
+const CURLOPT_CA_CACHE_TIMEOUT: CURLoption = curl_sys::CURLOPTTYPE_LONG + 321;
+
 /// Represents certificate information.
 /// `data` has format "name:content";
+/// `chain_length` is the number of certificates in the certificates chain.
 #[derive(Clone)]
 pub struct CertInfo {
     pub data: Vec<String>,
+    pub chain_length: usize,
 }
 
 /// Returns the information of the first certificate in the certificates chain.
@@ -56,7 +65,39 @@ pub fn get_certinfo(easy: &Easy) -> Result<Option<CertInfo>, Error> {
         }
         let slist = *((*certinfo).certinfo.offset(0));
         let data = to_list(slist);
-        Ok(Some(CertInfo { data }))
+        let chain_length = count as usize;
+        Ok(Some(CertInfo { data, chain_length }))
+    }
+}
+
+/// Verifies the status of the server certificate, using the "Certificate Status Request" TLS
+/// extension (aka. OCSP stapling).
+///
+/// Corresponds to [`CURLOPT_SSL_VERIFYSTATUS`], not exposed by the curl crate. This option may
+/// return an error if the TLS backend doesn't support it.
+pub fn ssl_verify_status(easy: &mut Easy, verify: bool) -> Result<(), Error> {
+    unsafe {
+        let rc =
+            curl_sys::curl_easy_setopt(easy.raw(), CURLOPT_SSL_VERIFYSTATUS, c_long::from(verify));
+        cvt(easy, rc)
+    }
+}
+
+/// Sets the life-time of the cached CA certificates store.
+///
+/// With a zero `timeout`, the CA certificates store is re-read for each new connection, so
+/// that changes of the system trust store (or of the `--cacert` file) are taken into account.
+///
+/// Corresponds to [`CURLOPT_CA_CACHE_TIMEOUT`], not exposed by the curl crate. This option is
+/// ignored by TLS backends that don't cache the CA certificates store.
+pub fn ca_cache_timeout(easy: &mut Easy, timeout: Duration) -> Result<(), Error> {
+    unsafe {
+        let rc = curl_sys::curl_easy_setopt(
+            easy.raw(),
+            CURLOPT_CA_CACHE_TIMEOUT,
+            timeout.as_secs() as c_long,
+        );
+        cvt(easy, rc)
     }
 }
 
@@ -172,6 +213,44 @@ pub fn total_time_t(easy: &mut Easy) -> Result<Duration, Error> {
     getopt_off_t(easy, CURLINFO_TOTAL_TIME_T).map(microseconds_to_duration)
 }
 
+/// Get the number of downloaded bytes.
+///
+/// Corresponds to [`CURLINFO_SIZE_DOWNLOAD_T`] and may return an error if the
+/// option isn't supported.
+pub fn size_download_t(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_off_t(easy, CURLINFO_SIZE_DOWNLOAD_T).map(|s| s as u64)
+}
+
+/// Get the average download speed, in bytes per second.
+///
+/// Corresponds to [`CURLINFO_SPEED_DOWNLOAD_T`] and may return an error if the
+/// option isn't supported.
+pub fn speed_download_t(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_off_t(easy, CURLINFO_SPEED_DOWNLOAD_T).map(|s| s as u64)
+}
+
+/// Get the average download speed, in bytes per second.
+///
+/// Corresponds to [`curl_sys::CURLINFO_SPEED_DOWNLOAD`], deprecated since libcurl 7.55.0.
+pub fn speed_download(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_double(easy, curl_sys::CURLINFO_SPEED_DOWNLOAD).map(|s| s as u64)
+}
+
+/// Get the average upload speed, in bytes per second.
+///
+/// Corresponds to [`CURLINFO_SPEED_UPLOAD_T`] and may return an error if the
+/// option isn't supported.
+pub fn speed_upload_t(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_off_t(easy, CURLINFO_SPEED_UPLOAD_T).map(|s| s as u64)
+}
+
+/// Get the average upload speed, in bytes per second.
+///
+/// Corresponds to [`curl_sys::CURLINFO_SPEED_UPLOAD`], deprecated since libcurl 7.55.0.
+pub fn speed_upload(easy: &mut Easy) -> Result<u64, Error> {
+    getopt_double(easy, curl_sys::CURLINFO_SPEED_UPLOAD).map(|s| s as u64)
+}
+
 /// Converts an instance of libcurl linked list [`curl_slist`] to a vec of [`String`].
 fn to_list(slist: *mut curl_slist) -> Vec<String> {
     let mut data = vec![];
@@ -211,6 +290,15 @@ fn getopt_off_t(easy: &mut Easy, opt: CURLINFO) -> Result<curl_off_t, Error> {
     }
 }
 
+fn getopt_double(easy: &mut Easy, opt: CURLINFO) -> Result<f64, Error> {
+    unsafe {
+        let mut p = 0 as c_double;
+        let rc = curl_sys::curl_easy_getinfo(easy.raw(), opt, &mut p);
+        cvt(easy, rc)?;
+        Ok(p)
+    }
+}
+
 fn microseconds_to_duration(microseconds: i64) -> Duration {
     Duration::from_micros(microseconds as u64)
 }
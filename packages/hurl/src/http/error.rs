@@ -42,11 +42,18 @@ pub enum HttpError {
         url: String,
     },
     TooManyRedirect,
+    RedirectLoop {
+        urls: Vec<String>,
+    },
     UnsupportedContentEncoding {
         description: String,
     },
     InvalidUrl(String),
     InvalidUrlPrefix(String),
+    WebSocketHandshake {
+        description: String,
+        url: String,
+    },
 }
 
 impl From<curl::Error> for HttpError {
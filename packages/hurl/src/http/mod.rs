@@ -25,10 +25,11 @@ pub use self::header::Header;
 pub(crate) use self::options::{ClientOptions, Verbosity};
 pub use self::request::Request;
 pub(crate) use self::request_spec::{Body, FileParam, Method, MultipartParam, RequestSpec};
-pub use self::response::{Response, Version};
+pub use self::response::{Redirect, Response, Version};
 #[cfg(test)]
 pub use self::tests::*;
 pub use self::timings::Timings;
+pub use self::transfer::Transfer;
 pub use self::version::libcurl_version_info;
 
 mod call;
@@ -55,4 +56,6 @@ mod response_decoding;
 mod tests;
 mod timings;
 mod timings_debug;
+mod transfer;
 mod version;
+mod websocket;
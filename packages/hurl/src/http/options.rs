@@ -34,6 +34,9 @@ pub struct ClientOptions {
     pub no_proxy: Option<String>,
     pub path_as_is: bool,
     pub proxy: Option<String>,
+    pub proxy_cacert_file: Option<String>,
+    /// Re-reads the CA certificates store for each connection
+    pub reload_ca_store: bool,
     pub resolves: Vec<String>,
     pub retry: Retry,
     pub ssl_no_revoke: bool,
@@ -41,6 +44,9 @@ pub struct ClientOptions {
     pub user: Option<String>,
     pub user_agent: Option<String>,
     pub verbosity: Option<Verbosity>,
+    /// Verifies the status of the server certificate with OCSP stapling
+    pub verify_status: bool,
+    pub websocket: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -66,6 +72,8 @@ impl Default for ClientOptions {
             no_proxy: None,
             path_as_is: false,
             proxy: None,
+            proxy_cacert_file: None,
+            reload_ca_store: false,
             resolves: vec![],
             retry: Retry::None,
             ssl_no_revoke: false,
@@ -73,6 +81,8 @@ impl Default for ClientOptions {
             user: None,
             user_agent: None,
             verbosity: None,
+            verify_status: false,
+            websocket: false,
         }
     }
 }
@@ -98,12 +108,15 @@ impl ClientOptions {
             arguments.push("--key".to_string());
             arguments.push(client_key_file.clone());
         }
+        if self.verify_status {
+            arguments.push("--cert-status".to_string());
+        }
         if self.compressed {
             arguments.push("--compressed".to_string());
         }
         if self.connect_timeout != ClientOptions::default().connect_timeout {
             arguments.push("--connect-timeout".to_string());
-            arguments.push(self.connect_timeout.as_secs().to_string());
+            arguments.push(self.connect_timeout.as_secs_f64().to_string());
         }
         for connect in self.connects_to.iter() {
             arguments.push("--connect-to".to_string());
@@ -134,13 +147,17 @@ impl ClientOptions {
             arguments.push("--proxy".to_string());
             arguments.push(format!("'{proxy}'"));
         }
+        if let Some(ref proxy_cacert_file) = self.proxy_cacert_file {
+            arguments.push("--proxy-cacert".to_string());
+            arguments.push(proxy_cacert_file.clone());
+        }
         for resolve in self.resolves.iter() {
             arguments.push("--resolve".to_string());
             arguments.push(resolve.clone());
         }
         if self.timeout != ClientOptions::default().timeout {
             arguments.push("--timeout".to_string());
-            arguments.push(self.timeout.as_secs().to_string());
+            arguments.push(self.timeout.as_secs_f64().to_string());
         }
         if let Some(ref user) = self.user {
             arguments.push("--user".to_string());
@@ -174,6 +191,8 @@ mod tests {
                 cookie_input_file: Some("cookie_file".to_string()),
                 path_as_is: true,
                 proxy: Some("localhost:3128".to_string()),
+                proxy_cacert_file: Some("proxy_cacert.pem".to_string()),
+                reload_ca_store: false,
                 no_proxy: None,
                 verbosity: None,
                 insecure: true,
@@ -188,9 +207,12 @@ mod tests {
                 user: Some("user:password".to_string()),
                 user_agent: Some("my-useragent".to_string()),
                 compressed: true,
+                verify_status: true,
+                websocket: false,
             }
             .curl_args(),
             [
+                "--cert-status".to_string(),
                 "--compressed".to_string(),
                 "--connect-timeout".to_string(),
                 "20".to_string(),
@@ -205,6 +227,8 @@ mod tests {
                 "--path-as-is".to_string(),
                 "--proxy".to_string(),
                 "'localhost:3128'".to_string(),
+                "--proxy-cacert".to_string(),
+                "proxy_cacert.pem".to_string(),
                 "--resolve".to_string(),
                 "foo.com:80:192.168.0.1".to_string(),
                 "--resolve".to_string(),
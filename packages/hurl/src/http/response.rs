@@ -18,20 +18,41 @@
 use core::fmt;
 use std::time::Duration;
 
+use chrono::{DateTime, Utc};
+
 use crate::http::certificate::Certificate;
-use crate::http::{header, Header};
+use crate::http::{header, Header, Transfer};
 
 /// Represents an HTTP response.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Response {
     pub version: Version,
     pub status: u32,
+    /// The reason phrase of the status line, empty if there is none (HTTP/2 for instance)
+    pub reason: String,
     pub headers: Vec<Header>,
     pub body: Vec<u8>,
     pub duration: Duration,
     pub url: String,
     /// The end-user certificate, in the response certificate chain
     pub certificate: Option<Certificate>,
+    /// The intermediate responses that have been followed to get this response
+    pub redirects: Vec<Redirect>,
+    /// The size and speed of the transfer
+    pub transfer: Transfer,
+    /// True if the TLS handshake of this response has resumed a previous TLS session, `None` if
+    /// the TLS backend of libcurl doesn't let us know it (see [`crate::http::Client`])
+    pub tls_resumed: Option<bool>,
+    /// The request line sent for this response (`GET /path HTTP/2` for instance)
+    pub request_line: String,
+}
+
+/// Represents an intermediate redirection response (a hop) of an HTTP response.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redirect {
+    pub url: String,
+    pub status: u32,
+    pub headers: Vec<Header>,
 }
 
 impl Default for Response {
@@ -39,11 +60,16 @@ impl Default for Response {
         Response {
             version: Version::Http10,
             status: 200,
+            reason: String::new(),
             headers: vec![],
             body: vec![],
             duration: Default::default(),
             url: String::new(),
             certificate: None,
+            redirects: vec![],
+            transfer: Transfer::default(),
+            tls_resumed: None,
+            request_line: String::new(),
         }
     }
 }
@@ -67,17 +93,142 @@ impl fmt::Display for Version {
 }
 
 impl Response {
+    /// Serializes this response in an HTTP/1.x-style format, using the negotiated version string
+    /// (`HTTP/2 204` for an HTTP/2 response): the status line, the headers, a blank line and the
+    /// body bytes, as they have been received.
+    pub fn wire_bytes(&self) -> Vec<u8> {
+        let mut head = format!("{} {}", self.version, self.status);
+        if !self.reason.is_empty() {
+            head.push(' ');
+            head.push_str(&self.reason);
+        }
+        head.push_str("\r\n");
+        for header in self.headers.iter() {
+            head.push_str(&format!("{}: {}\r\n", header.name, header.value));
+        }
+        head.push_str("\r\n");
+        let mut bytes = head.into_bytes();
+        bytes.extend_from_slice(&self.body);
+        bytes
+    }
+
     /// Returns all header values.
     pub fn get_header_values(&self, name: &str) -> Vec<String> {
         header::get_values(&self.headers, name)
     }
 
+    /// Returns the value of the pseudo-header `name` (like `:status`).
+    ///
+    /// Pseudo-headers only exist in HTTP/2 responses and are not part of the response headers.
+    /// libcurl doesn't expose them, so they're rebuilt from the response fields.
+    pub fn get_pseudo_header(&self, name: &str) -> Option<String> {
+        match (&self.version, name) {
+            (Version::Http2, ":status") => Some(self.status.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the `Content-Length` header, if there is a valid one.
+    pub fn content_length(&self) -> Option<u64> {
+        header::get_values(&self.headers, "Content-Length")
+            .first()
+            .and_then(|v| v.trim().parse().ok())
+    }
+
+    /// Returns the number of bytes of the body as sent over the wire, including the bytes
+    /// received after the declared `Content-Length`.
+    pub fn body_length(&self) -> u64 {
+        self.body.len() as u64 + self.transfer.size_excess
+    }
+
     /// Returns optional Content-type header value.
     pub fn content_type(&self) -> Option<String> {
         header::get_values(&self.headers, "Content-Type")
             .get(0)
             .cloned()
     }
+
+    /// Returns the media type of the Content-Type header, lowercased and without its parameters
+    /// (like `charset`).
+    pub fn media_type(&self) -> Option<String> {
+        self.content_type().map(|content_type| {
+            let media_type = content_type.split(';').next().unwrap_or_default();
+            media_type.trim().to_lowercase()
+        })
+    }
+
+    /// Returns the value of the directive `name` of the Cache-Control headers, in seconds.
+    fn cache_control_seconds(&self, name: &str) -> Option<u64> {
+        header::get_values(&self.headers, "Cache-Control")
+            .iter()
+            .flat_map(|value| value.split(','))
+            .find_map(|directive| {
+                let (directive_name, value) = directive.split_once('=')?;
+                if !directive_name.trim().eq_ignore_ascii_case(name) {
+                    return None;
+                }
+                value.trim().trim_matches('"').parse().ok()
+            })
+    }
+
+    /// Returns the freshness lifetime of this response, in seconds: the `s-maxage` directive of
+    /// the Cache-Control headers, or else the `max-age` directive, or else the `Expires` header
+    /// minus the `Date` header. There is none without any of them.
+    ///
+    /// See <https://www.rfc-editor.org/rfc/rfc9111#section-4.2.1>
+    pub fn freshness_lifetime(&self) -> Option<u64> {
+        if let Some(s_maxage) = self.cache_control_seconds("s-maxage") {
+            return Some(s_maxage);
+        }
+        if let Some(max_age) = self.cache_control_seconds("max-age") {
+            return Some(max_age);
+        }
+        let expires = header::get_values(&self.headers, "Expires");
+        let expires = expires.first()?;
+        // An invalid Expires (like "0") means the response is already expired.
+        let Ok(expires) = DateTime::parse_from_rfc2822(expires.trim()) else {
+            return Some(0);
+        };
+        let date = header::get_values(&self.headers, "Date")
+            .first()
+            .and_then(|v| DateTime::parse_from_rfc2822(v.trim()).ok())?;
+        Some((expires - date).num_seconds().max(0) as u64)
+    }
+
+    /// Returns the age of this response at `now`, in seconds: the greatest of the `Age` header and
+    /// the time elapsed since the `Date` header.
+    ///
+    /// See <https://www.rfc-editor.org/rfc/rfc9111#section-4.2.3>
+    pub fn current_age(&self, now: DateTime<Utc>) -> u64 {
+        let age = header::get_values(&self.headers, "Age")
+            .first()
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .unwrap_or(0);
+        let apparent_age = header::get_values(&self.headers, "Date")
+            .first()
+            .and_then(|v| DateTime::parse_from_rfc2822(v.trim()).ok())
+            .map(|date| (now - date.with_timezone(&Utc)).num_seconds().max(0) as u64)
+            .unwrap_or(0);
+        age.max(apparent_age)
+    }
+
+    /// Returns true if this response is fresh at `now`: its freshness lifetime is greater than
+    /// its current age.
+    ///
+    /// See <https://www.rfc-editor.org/rfc/rfc9111#section-4.2>
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        match self.freshness_lifetime() {
+            Some(lifetime) => lifetime > self.current_age(now),
+            None => false,
+        }
+    }
+}
+
+impl Redirect {
+    /// Returns all header values.
+    pub fn get_header_values(&self, name: &str) -> Vec<String> {
+        header::get_values(&self.headers, name)
+    }
 }
 
 #[cfg(test)]
@@ -96,4 +247,131 @@ mod tests {
         );
         assert!(response.get_header_values("Unknown").is_empty());
     }
+
+    #[test]
+    fn test_media_type() {
+        let response = |value| Response {
+            headers: vec![Header::new("Content-Type", value)],
+            ..Default::default()
+        };
+        assert_eq!(
+            response("application/json").media_type(),
+            Some("application/json".to_string())
+        );
+        assert_eq!(
+            response("Text/HTML; charset=utf-8").media_type(),
+            Some("text/html".to_string())
+        );
+        assert_eq!(Response::default().media_type(), None);
+    }
+
+    #[test]
+    fn test_freshness() {
+        let now = DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&Utc);
+        let response = |headers: &[(&str, &str)]| Response {
+            headers: headers
+                .iter()
+                .map(|(name, value)| Header::new(name, value))
+                .collect(),
+            ..Default::default()
+        };
+
+        let fresh = response(&[
+            ("Cache-Control", "public, MAX-AGE=\"600\""),
+            ("Date", "Wed, 21 Oct 2015 07:26:00 GMT"),
+        ]);
+        assert_eq!(fresh.freshness_lifetime(), Some(600));
+        assert_eq!(fresh.current_age(now), 120);
+        assert!(fresh.is_fresh(now));
+
+        // The greatest of Age and the time elapsed since Date wins.
+        let stale = response(&[
+            ("Cache-Control", "no-transform"),
+            ("Cache-Control", "max-age=600"),
+            ("Date", "Wed, 21 Oct 2015 07:26:00 GMT"),
+            ("Age", "900"),
+        ]);
+        assert_eq!(stale.freshness_lifetime(), Some(600));
+        assert_eq!(stale.current_age(now), 900);
+        assert!(!stale.is_fresh(now));
+
+        let stale = response(&[
+            ("Cache-Control", "max-age=60"),
+            ("Date", "Wed, 21 Oct 2015 06:28:00 GMT"),
+        ]);
+        assert_eq!(stale.freshness_lifetime(), Some(60));
+        assert!(!stale.is_fresh(now));
+
+        // s-maxage takes precedence over max-age, and max-age over Expires.
+        let response_s_maxage = response(&[
+            ("Cache-Control", "max-age=60, s-maxage=300"),
+            ("Date", "Wed, 21 Oct 2015 07:26:00 GMT"),
+            ("Expires", "Wed, 21 Oct 2015 08:26:00 GMT"),
+        ]);
+        assert_eq!(response_s_maxage.freshness_lifetime(), Some(300));
+        assert!(response_s_maxage.is_fresh(now));
+
+        let response_max_age = response(&[
+            ("Cache-Control", "max-age=60"),
+            ("Date", "Wed, 21 Oct 2015 07:26:00 GMT"),
+            ("Expires", "Wed, 21 Oct 2015 08:26:00 GMT"),
+        ]);
+        assert_eq!(response_max_age.freshness_lifetime(), Some(60));
+        assert!(!response_max_age.is_fresh(now));
+
+        let response_expires = response(&[
+            ("Date", "Wed, 21 Oct 2015 07:26:00 GMT"),
+            ("Expires", "Wed, 21 Oct 2015 08:26:00 GMT"),
+        ]);
+        assert_eq!(response_expires.freshness_lifetime(), Some(3600));
+        assert!(response_expires.is_fresh(now));
+
+        let response_expired = response(&[
+            ("Date", "Wed, 21 Oct 2015 07:26:00 GMT"),
+            ("Expires", "Wed, 21 Oct 2015 06:26:00 GMT"),
+        ]);
+        assert_eq!(response_expired.freshness_lifetime(), Some(0));
+        assert!(!response_expired.is_fresh(now));
+
+        let response_invalid_expires =
+            response(&[("Date", "Wed, 21 Oct 2015 07:26:00 GMT"), ("Expires", "0")]);
+        assert_eq!(response_invalid_expires.freshness_lifetime(), Some(0));
+        assert!(!response_invalid_expires.is_fresh(now));
+
+        let response = response(&[("Cache-Control", "no-store"), ("Age", "10")]);
+        assert_eq!(response.freshness_lifetime(), None);
+        assert_eq!(response.current_age(now), 10);
+        assert!(!response.is_fresh(now));
+    }
+
+    #[test]
+    fn test_wire_bytes() {
+        let response = Response {
+            version: Version::Http11,
+            status: 200,
+            reason: "OK".to_string(),
+            headers: vec![
+                Header::new("Content-Type", "application/octet-stream"),
+                Header::new("Content-Length", "3"),
+            ],
+            body: vec![0xff, 0x00, 0x0a],
+            ..Default::default()
+        };
+        let mut expected = b"HTTP/1.1 200 OK\r\n\
+Content-Type: application/octet-stream\r\n\
+Content-Length: 3\r\n\
+\r\n"
+            .to_vec();
+        expected.extend_from_slice(&[0xff, 0x00, 0x0a]);
+        assert_eq!(response.wire_bytes(), expected);
+
+        let response = Response {
+            version: Version::Http2,
+            status: 204,
+            ..Default::default()
+        };
+        assert_eq!(response.wire_bytes(), b"HTTP/2 204\r\n\r\n".to_vec());
+    }
 }
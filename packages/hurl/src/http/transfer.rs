@@ -0,0 +1,119 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use curl::easy::Easy;
+
+use crate::http::easy_ext;
+use crate::util::logger::Logger;
+
+/// Size and speed information for an HTTP transfer.
+// See [`easy_ext::size_download_t`], [`easy_ext::speed_download_t`] and [`easy_ext::speed_upload_t`]
+// for fields definition.
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct Transfer {
+    /// Number of bytes downloaded (the response body, as sent over the wire)
+    pub size_download: u64,
+    /// Average download speed in bytes per second
+    pub speed_download: u64,
+    /// Average upload speed in bytes per second
+    pub speed_upload: u64,
+    /// Number of bytes received after the response body, as declared by its `Content-Length`
+    /// header. These bytes are discarded by libcurl.
+    pub size_excess: u64,
+}
+
+impl Transfer {
+    pub fn new(easy: &mut Easy, size_excess: u64) -> Self {
+        // As with timings, we try the *_t functions of libcurl (available for libcurl >= 7.55.0)
+        // and fallback to the functions returning a double if *_t are not available.
+        let size_download = easy_ext::size_download_t(easy)
+            .or(easy.download_size().map(|s| s as u64))
+            .unwrap_or_default();
+        let speed_download = easy_ext::speed_download_t(easy)
+            .or(easy_ext::speed_download(easy))
+            .unwrap_or_default();
+        let speed_upload = easy_ext::speed_upload_t(easy)
+            .or(easy_ext::speed_upload(easy))
+            .unwrap_or_default();
+        Transfer {
+            size_download,
+            speed_download,
+            speed_upload,
+            size_excess,
+        }
+    }
+
+    /// Logs the transfer throughput.
+    pub fn log(&self, logger: &Logger) {
+        logger.debug(&format!(
+            "Transfer: {} bytes downloaded at {} bytes/s, upload at {} bytes/s",
+            self.size_download, self.speed_download, self.speed_upload
+        ));
+    }
+}
+
+/// Returns the number of excess bytes reported by a libcurl debug `text`, when more data than
+/// the `Content-Length` of the response has been received.
+///
+/// libcurl has no API for excess bytes, so this is the only place where they're scraped from its
+/// debug texts, which are not stable across versions:
+/// - libcurl 7.62.0 to 8.3.0 (`lib/transfer.c`) logs `Excess found in a read: excess = 7, ...`,
+///   or `Excess found: excess = 7 url = ...` for a zero-length body,
+/// - libcurl before 7.62.0 logs `Excess found in a non pipelined read: excess = 7, ...`,
+/// - newer libcurl versions log `Excess found writing body: excess = 7, ...`.
+///
+/// libcurl 8.3.0 only reports excess bytes received with the response headers: a body read after
+/// the headers is limited to the `Content-Length`. So an excess found is accurate, but an excess
+/// can be missed, which makes this a best-effort detection.
+pub fn parse_excess(text: &str) -> Option<u64> {
+    if !text.starts_with("Excess found") {
+        return None;
+    }
+    let (_, excess) = text.split_once("excess = ")?;
+    let excess = excess.split(|c: char| !c.is_ascii_digit()).next()?;
+    excess.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_excess() {
+        assert_eq!(
+            parse_excess(
+                "Excess found in a read: excess = 7, size = 5, maxdownload = 5, bytecount = 0"
+            ),
+            Some(7)
+        );
+        assert_eq!(
+            parse_excess(
+                "Excess found writing body: excess = 12, size = 5, maxdownload = 5, bytecount = 5"
+            ),
+            Some(12)
+        );
+        assert_eq!(
+            parse_excess("Excess found: excess = 3 url = /hello (zero-length body)"),
+            Some(3)
+        );
+        assert_eq!(
+            parse_excess("Excess found in a non pipelined read: excess = 2, size = 5"),
+            Some(2)
+        );
+        assert_eq!(parse_excess("Closing connection 0"), None);
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Minimal support of the WebSocket opening handshake (see <https://www.rfc-editor.org/rfc/rfc6455#section-4>).
+//!
+//! Only the HTTP Upgrade handshake is performed, no frame is exchanged after the `101 Switching Protocols`
+//! response.
+use base64::engine::general_purpose;
+use base64::Engine;
+use sha1::{Digest, Sha1};
+use uuid::Uuid;
+
+/// GUID concatenated to the client key to compute the server `Sec-WebSocket-Accept` value.
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Returns a new random `Sec-WebSocket-Key` value: a base64-encoded 16-byte nonce.
+pub fn generate_key() -> String {
+    let nonce = Uuid::new_v4();
+    general_purpose::STANDARD.encode(nonce.as_bytes())
+}
+
+/// Returns the `Sec-WebSocket-Accept` value that a server must send for this client `key`.
+pub fn accept_key(key: &str) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(key.as_bytes());
+    hasher.update(WEBSOCKET_GUID.as_bytes());
+    general_purpose::STANDARD.encode(hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_key() {
+        let key = generate_key();
+        let nonce = general_purpose::STANDARD.decode(&key).unwrap();
+        assert_eq!(nonce.len(), 16);
+        assert_ne!(key, generate_key());
+    }
+
+    #[test]
+    fn test_accept_key() {
+        // Sample from RFC 6455
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzhZRbK+xOo="
+        );
+    }
+}
@@ -16,6 +16,7 @@
  *
  */
 use chrono::{DateTime, Utc};
+use hurl_core::error::Error;
 use serde_json::Number;
 
 use crate::http::{
@@ -37,11 +38,30 @@ impl HurlResult {
             "filename".to_string(),
             serde_json::Value::String(filename.to_string()),
         );
-        let entries = self
-            .entries
-            .iter()
-            .map(|e| e.to_json(filename, content))
-            .collect();
+        let mut entries = vec![];
+        let mut attempts = vec![];
+        for (i, entry) in self.entries.iter().enumerate() {
+            let mut json = entry.to_json(filename, content);
+            // A retried entry is followed by its next attempts, the last attempt gets the timeline
+            // of all of them.
+            if entry.retries == 0 {
+                attempts.clear();
+            }
+            attempts.push(entry.attempt_json());
+            let last_attempt = match self.entries.get(i + 1) {
+                Some(next) => next.entry_index != entry.entry_index || next.retries == 0,
+                None => true,
+            };
+            if last_attempt && entry.retries > 0 {
+                if let serde_json::Value::Object(map) = &mut json {
+                    map.insert(
+                        "attempts".to_string(),
+                        serde_json::Value::Array(attempts.clone()),
+                    );
+                }
+            }
+            entries.push(json);
+        }
         map.insert("entries".to_string(), serde_json::Value::Array(entries));
         map.insert("success".to_string(), serde_json::Value::Bool(self.success));
         map.insert(
@@ -76,6 +96,48 @@ impl EntryResult {
             "time".to_string(),
             serde_json::Value::Number(serde_json::Number::from(self.time_in_ms as u64)),
         );
+        if self.slow {
+            map.insert("slow".to_string(), serde_json::Value::Bool(true));
+        }
+        if self.retries > 0 {
+            map.insert(
+                "retries".to_string(),
+                serde_json::Value::Number(serde_json::Number::from(self.retries)),
+            );
+        }
+        serde_json::Value::Object(map)
+    }
+}
+
+impl EntryResult {
+    /// Serializes the outcome of this run of the entry: its start time, its duration, the last
+    /// response status and the first error if any.
+    fn attempt_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "timestamp".to_string(),
+            serde_json::Value::String(self.timestamp.to_string()),
+        );
+        map.insert(
+            "time".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.time_in_ms as u64)),
+        );
+        if let Some(call) = self.calls.last() {
+            map.insert(
+                "status".to_string(),
+                serde_json::Value::Number(serde_json::Number::from(call.response.status)),
+            );
+        }
+        if let Some(error) = self.errors.first() {
+            map.insert(
+                "error".to_string(),
+                serde_json::Value::String(error.description()),
+            );
+        }
+        map.insert(
+            "success".to_string(),
+            serde_json::Value::Bool(self.errors.is_empty()),
+        );
         serde_json::Value::Object(map)
     }
 }
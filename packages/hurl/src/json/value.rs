@@ -22,6 +22,10 @@ use base64::Engine;
 use crate::runner::Value;
 
 impl Value {
+    /// Serializes this value to JSON.
+    ///
+    /// Objects keys are serialized sorted (a `serde_json::Map` is ordered by keys), so the JSON
+    /// produced for a given value is byte-stable across runs.
     pub fn to_json(&self) -> serde_json::Value {
         match self {
             Value::Bool(v) => serde_json::Value::Bool(*v),
@@ -60,3 +64,41 @@ impl Value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_keys_order() {
+        let object = |key_values: &[(&str, i64)]| {
+            Value::Object(
+                key_values
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), Value::Integer(*v)))
+                    .collect(),
+            )
+        };
+        let value = Value::List(vec![
+            object(&[("zeta", 1), ("alpha", 2), ("mid", 3)]),
+            Value::Object(vec![
+                ("b".to_string(), object(&[("y", 1), ("x", 2)])),
+                ("a".to_string(), Value::Null),
+            ]),
+        ]);
+        let expected = r#"[{"alpha":2,"mid":3,"zeta":1},{"a":null,"b":{"x":2,"y":1}}]"#;
+        for _ in 0..10 {
+            assert_eq!(value.to_json().to_string(), expected);
+        }
+
+        // Keys insertion order doesn't change the serialization.
+        assert_eq!(
+            object(&[("mid", 3), ("zeta", 1), ("alpha", 2)])
+                .to_json()
+                .to_string(),
+            object(&[("zeta", 1), ("alpha", 2), ("mid", 3)])
+                .to_json()
+                .to_string(),
+        );
+    }
+}
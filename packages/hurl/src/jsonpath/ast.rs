@@ -28,8 +28,9 @@ pub struct Query {
 pub enum Selector {
     Wildcard,
     NameChild(String),
-    ArrayIndex(usize),        // one unique index
-    ArrayIndices(Vec<usize>), // two or more indexes (separated by comma)
+    NameChildren(Vec<String>), // two or more names (separated by comma)
+    ArrayIndex(usize),         // one unique index
+    ArrayIndices(Vec<usize>),  // two or more indexes (separated by comma)
     ArraySlice(Slice),
     ArrayWildcard,
     Filter(Predicate),
@@ -132,6 +132,15 @@ impl Selector {
                 };
                 Some(JsonpathResult::Collection(elements))
             }
+            Selector::NameChildren(fields) => {
+                let mut values = vec![];
+                for field in fields {
+                    if let Some(value) = root.get(field) {
+                        values.push(value.clone())
+                    }
+                }
+                Some(JsonpathResult::Collection(values))
+            }
             Selector::ArrayIndices(indexes) => {
                 let mut values = vec![];
                 for index in indexes {
@@ -277,6 +286,20 @@ mod tests {
             .is_none(),);
     }
 
+    #[test]
+    pub fn test_selector_name_children() {
+        assert_eq!(
+            Selector::NameChildren(vec![
+                "title".to_string(),
+                "undefined".to_string(),
+                "author".to_string()
+            ])
+            .eval(&json_first_book())
+            .unwrap(),
+            JsonpathResult::Collection(vec![json!("Sayings of the Century"), json!("Nigel Rees")])
+        );
+    }
+
     #[test]
     pub fn test_selector_array_index() {
         assert_eq!(
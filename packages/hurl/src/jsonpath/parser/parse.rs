@@ -169,8 +169,23 @@ fn selector_object_key_bracket(reader: &mut Reader) -> Result<Selector, Error> {
             },
         }),
         Ok(v) => {
+            let mut names = vec![v];
+            loop {
+                let state = reader.state.clone();
+                if try_literal(",", reader).is_ok() {
+                    names.push(string_value(reader)?);
+                } else {
+                    reader.state = state;
+                    break;
+                }
+            }
             literal("]", reader)?;
-            Ok(Selector::NameChild(v))
+            let selector = if names.len() == 1 {
+                Selector::NameChild(names.pop().unwrap())
+            } else {
+                Selector::NameChildren(names)
+            };
+            Ok(selector)
         }
     }
 }
@@ -533,6 +548,13 @@ mod tests {
             Selector::NameChild("key1".to_string())
         );
         assert_eq!(reader.state.cursor, 8);
+
+        let mut reader = Reader::new("['key1','key2']");
+        assert_eq!(
+            selector(&mut reader).unwrap(),
+            Selector::NameChildren(vec!["key1".to_string(), "key2".to_string()])
+        );
+        assert_eq!(reader.state.cursor, 15);
     }
 
     #[test]
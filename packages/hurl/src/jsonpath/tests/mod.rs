@@ -325,6 +325,21 @@ fn test_array() {
     );
 }
 
+#[test]
+fn test_union() {
+    let expr = jsonpath::parse("$.store.book[0]['title','author']").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("Sayings of the Century"), json!("Nigel Rees")])
+    );
+
+    let expr = jsonpath::parse("$.store.book[3,1].author").unwrap();
+    assert_eq!(
+        expr.eval(&bookstore_value()).unwrap(),
+        JsonpathResult::Collection(vec![json!("J. R. R. Tolkien"), json!("Evelyn Waugh")])
+    );
+}
+
 #[test]
 fn test_key_access() {
     let obj = json!({
@@ -17,6 +17,7 @@
  */
 mod cli;
 
+use std::collections::HashMap;
 use std::io::prelude::*;
 use std::path::Path;
 use std::time::Instant;
@@ -24,8 +25,8 @@ use std::{env, process};
 
 use crate::cli::options::OptionsError;
 use colored::control;
-use hurl::report::{html, junit, tap};
-use hurl::runner::HurlResult;
+use hurl::report::{html, junit, markdown, tap};
+use hurl::runner::{HurlResult, Value};
 use hurl::util::logger::{BaseLogger, Logger, LoggerOptionsBuilder, Verbosity};
 use hurl::{output, runner};
 
@@ -73,6 +74,7 @@ fn main() {
     let current_dir = current_dir.as_path();
     let start = Instant::now();
     let mut runs = vec![];
+    let mut variables = opts.variables.clone();
 
     for (current, filename) in opts.input_files.iter().enumerate() {
         // We check the input file existence and check that we can read its contents.
@@ -84,6 +86,20 @@ fn main() {
         let content = cli::read_to_string(filename.as_str());
         let content = unwrap_or_exit(content, EXIT_ERROR_PARSING, &base_logger);
 
+        // With --reload-variables, variables files are read again so each Hurl file uses their
+        // latest values.
+        if opts.reload_variables && current > 0 {
+            variables = match cli::options::reload_variables(&opts) {
+                Ok(v) => v,
+                Err(e) => {
+                    let message = match e {
+                        OptionsError::Info(message) | OptionsError::Error(message) => message,
+                    };
+                    exit_with_error(&message, EXIT_ERROR_COMMANDLINE, &base_logger);
+                }
+            };
+        }
+
         let verbosity = Verbosity::from(opts.verbose, opts.very_verbose);
         let logger_options = LoggerOptionsBuilder::new()
             .color(opts.color)
@@ -98,7 +114,7 @@ fn main() {
         let total = opts.input_files.len();
         logger.test_running(current + 1, total);
         // Run our Hurl file now
-        let hurl_result = execute(&content, filename, current_dir, &opts);
+        let hurl_result = execute(&content, filename, current_dir, &opts, &variables);
         let hurl_result = match hurl_result {
             Ok(h) => h,
             Err(_) => process::exit(EXIT_ERROR_PARSING),
@@ -141,6 +157,12 @@ fn main() {
         unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
     }
 
+    if let Some(filename) = opts.markdown_file {
+        base_logger.debug(format!("Writing Markdown report to {filename}").as_str());
+        let result = create_markdown_report(&runs, &filename);
+        unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
+    }
+
     if let Some(filename) = opts.tap_file {
         base_logger.debug(format!("Writing TAP report to {filename}").as_str());
         let result = create_tap_report(&runs, &filename);
@@ -153,6 +175,18 @@ fn main() {
         unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
     }
 
+    if let Some(dir) = opts.output_dir {
+        base_logger.debug(format!("Writing response bodies to {}", dir.display()).as_str());
+        let result = create_output_dir(&runs, &dir);
+        unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
+    }
+
+    if let Some(filename) = opts.dump_response_file {
+        base_logger.debug(format!("Writing responses to {filename}").as_str());
+        let result = create_response_dump(&runs, &filename);
+        unwrap_or_exit(result, EXIT_ERROR_UNDEFINED, &base_logger);
+    }
+
     if let Some(filename) = opts.cookie_output_file {
         base_logger.debug(format!("Writing cookies to {filename}").as_str());
         let result = create_cookies_file(&runs, &filename);
@@ -174,8 +208,8 @@ fn execute(
     filename: &str,
     current_dir: &Path,
     cli_options: &cli::options::Options,
+    variables: &HashMap<String, Value>,
 ) -> Result<HurlResult, String> {
-    let variables = &cli_options.variables;
     let runner_options = cli_options.to_runner_options(filename, current_dir);
     let logger_options = cli_options.to_logger_options(filename);
     runner::run(content, &runner_options, variables, &logger_options)
@@ -221,11 +255,21 @@ fn create_junit_report(runs: &[HurlRun], filename: &str) -> Result<(), cli::CliE
     Ok(())
 }
 
+/// Create a Markdown report for this run.
+fn create_markdown_report(runs: &[HurlRun], filename: &str) -> Result<(), cli::CliError> {
+    let testcases: Vec<markdown::Testcase> = runs
+        .iter()
+        .map(|r| markdown::Testcase::from(&r.hurl_result, &r.content, &r.filename))
+        .collect();
+    markdown::write_report(filename, &testcases)?;
+    Ok(())
+}
+
 /// Create a TAP report for this run.
 fn create_tap_report(runs: &[HurlRun], filename: &str) -> Result<(), cli::CliError> {
     let testcases: Vec<tap::Testcase> = runs
         .iter()
-        .map(|r| tap::Testcase::from(&r.hurl_result, &r.filename))
+        .map(|r| tap::Testcase::from(&r.hurl_result, &r.content, &r.filename))
         .collect();
     tap::write_report(filename, &testcases)?;
     Ok(())
@@ -246,6 +290,29 @@ fn create_html_report(runs: &[HurlRun], dir_path: &Path) -> Result<(), cli::CliE
     Ok(())
 }
 
+/// Writes the response bodies of this run, and their manifest, to the directory `dir_path`.
+fn create_output_dir(runs: &[HurlRun], dir_path: &Path) -> Result<(), cli::CliError> {
+    let mut entries = vec![];
+    for (index, run) in runs.iter().enumerate() {
+        let mut run_entries =
+            output::write_bodies(&run.hurl_result, &run.filename, index + 1, dir_path)?;
+        entries.append(&mut run_entries);
+    }
+    output::write_manifest(dir_path, &entries)?;
+    Ok(())
+}
+
+/// Writes every response of this run, in HTTP/1.1 wire format, to the file `filename`.
+fn create_response_dump(runs: &[HurlRun], filename: &str) -> Result<(), cli::CliError> {
+    let mut bytes = vec![];
+    for run in runs.iter() {
+        bytes.append(&mut output::dump_responses(&run.hurl_result));
+    }
+    std::fs::write(filename, bytes).map_err(|e| cli::CliError {
+        message: format!("Issue writing to {filename}: {e:?}"),
+    })
+}
+
 /// Returns an exit code for a list of HurlResult.
 fn exit_code(runs: &[HurlRun]) -> i32 {
     let mut count_errors_runner = 0;
@@ -336,6 +403,9 @@ pub mod tests {
                 errors: vec![],
                 time_in_ms: 0,
                 compressed: false,
+                slow: false,
+                retries: 0,
+                timestamp: chrono::Utc::now(),
             };
             HurlRun {
                 content: String::new(),
@@ -0,0 +1,208 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::path::Path;
+
+use crate::output;
+use crate::output::raw::body_bytes;
+use crate::runner::HurlResult;
+
+/// The name of the index file written in the output directory.
+pub const MANIFEST_FILENAME: &str = "manifest.json";
+
+/// A response body written in the output directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// The Hurl file of this entry
+    pub filename: String,
+    /// The index of the entry in its Hurl file (starting at 1)
+    pub index: usize,
+    pub url: String,
+    pub status: u32,
+    /// The name of the body file, relative to the output directory
+    pub file: String,
+    /// The size in bytes of the body file
+    pub size: usize,
+}
+
+impl ManifestEntry {
+    fn to_json(&self) -> serde_json::Value {
+        let mut map = serde_json::Map::new();
+        map.insert(
+            "filename".to_string(),
+            serde_json::Value::String(self.filename.clone()),
+        );
+        map.insert(
+            "index".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.index)),
+        );
+        map.insert(
+            "url".to_string(),
+            serde_json::Value::String(self.url.clone()),
+        );
+        map.insert(
+            "status".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.status)),
+        );
+        map.insert(
+            "file".to_string(),
+            serde_json::Value::String(self.file.clone()),
+        );
+        map.insert(
+            "size".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(self.size)),
+        );
+        serde_json::Value::Object(map)
+    }
+}
+
+/// Writes the last response body of each entry of `hurl_result` to the directory `dir`.
+///
+/// Body files are named `<run>-<entry>.body`, `run_index` being the index of the Hurl file
+/// in the current run (starting at 1). A retried entry is written only once, with its last run.
+/// Returns the entries to be listed in the manifest.
+pub fn write_bodies(
+    hurl_result: &HurlResult,
+    filename_in: &str,
+    run_index: usize,
+    dir: &Path,
+) -> Result<Vec<ManifestEntry>, output::Error> {
+    let mut entries = vec![];
+    let mut next_entries = hurl_result.entries.iter().skip(1);
+    for entry_result in hurl_result.entries.iter() {
+        if let Some(next) = next_entries.next() {
+            if next.entry_index == entry_result.entry_index {
+                continue;
+            }
+        }
+        let Some(call) = entry_result.calls.last() else {
+            continue;
+        };
+        let response = &call.response;
+        let body = body_bytes(response, entry_result.compressed)?;
+        let file = format!("{run_index}-{}.body", entry_result.entry_index);
+        write_file(&dir.join(&file), &body)?;
+        entries.push(ManifestEntry {
+            filename: filename_in.to_string(),
+            index: entry_result.entry_index,
+            url: response.url.clone(),
+            status: response.status,
+            file,
+            size: body.len(),
+        });
+    }
+    Ok(entries)
+}
+
+/// Writes the manifest file, listing all the body files `entries`, to the directory `dir`.
+pub fn write_manifest(dir: &Path, entries: &[ManifestEntry]) -> Result<(), output::Error> {
+    let entries = entries.iter().map(|e| e.to_json()).collect();
+    let mut map = serde_json::Map::new();
+    map.insert("entries".to_string(), serde_json::Value::Array(entries));
+    let manifest = serde_json::Value::Object(map);
+    let mut bytes = serde_json::to_vec_pretty(&manifest).unwrap();
+    bytes.push(b'\n');
+    write_file(&dir.join(MANIFEST_FILENAME), &bytes)
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<(), output::Error> {
+    std::fs::write(path, bytes).map_err(|e| output::Error {
+        message: format!("Issue writing to {}: {e:?}", path.display()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::http::{Call, Request, Response, Timings};
+    use crate::runner::EntryResult;
+
+    fn entry_result(entry_index: usize, retries: usize, body: &str) -> EntryResult {
+        let url = format!("http://localhost:8000/retry/{entry_index}");
+        EntryResult {
+            entry_index,
+            calls: vec![Call {
+                request: Request {
+                    url: url.clone(),
+                    method: "GET".to_string(),
+                    headers: vec![],
+                    body: vec![],
+                },
+                response: Response {
+                    url,
+                    body: body.as_bytes().to_vec(),
+                    ..Default::default()
+                },
+                timings: Timings::default(),
+            }],
+            captures: vec![],
+            asserts: vec![],
+            errors: vec![],
+            time_in_ms: 0,
+            compressed: false,
+            slow: false,
+            retries,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_write_bodies_with_retry() {
+        let dir = std::env::temp_dir().join(format!("hurl-output-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let hurl_result = HurlResult {
+            entries: vec![
+                entry_result(1, 0, "first"),
+                entry_result(2, 0, "attempt 1"),
+                entry_result(2, 1, "attempt 2"),
+                entry_result(2, 2, "attempt 3"),
+                entry_result(3, 0, "last"),
+            ],
+            time_in_ms: 0,
+            success: true,
+            cookies: vec![],
+        };
+
+        let entries = write_bodies(&hurl_result, "retry.hurl", 1, &dir).unwrap();
+        let files = entries.iter().map(|e| e.file.as_str()).collect::<Vec<_>>();
+        assert_eq!(files, ["1-1.body", "1-2.body", "1-3.body"]);
+        assert_eq!(entries[1].size, 9);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("1-2.body")).unwrap(),
+            "attempt 3"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_entry_to_json() {
+        let entry = ManifestEntry {
+            filename: "tests/hello.hurl".to_string(),
+            index: 2,
+            url: "http://localhost:8000/hello".to_string(),
+            status: 200,
+            file: "1-2.body".to_string(),
+            size: 13,
+        };
+        assert_eq!(
+            entry.to_json().to_string(),
+            r#"{"file":"1-2.body","filename":"tests/hello.hurl","index":2,"size":13,"status":200,"url":"http://localhost:8000/hello"}"#
+        );
+    }
+}
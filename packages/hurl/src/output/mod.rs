@@ -21,6 +21,11 @@
 //! - JSON: the whole run is serialized to JSON (like the [HAR](https://en.wikipedia.org/wiki/HAR_(file_format)) format)
 //! - raw: the last response of a run is serialized to a file. The body can be automatically uncompress
 //! or written as it.
+//! - directory: every response body is written to a directory, alongside a `manifest.json`
+//!   index of the written files.
+//! - dump: every response is written to a file in HTTP/1.1 wire format (status line, headers and
+//!   body).
+mod dir;
 mod json;
 mod raw;
 mod stdout;
@@ -29,8 +34,9 @@ use std::fmt;
 use std::io::Write;
 use std::path::Path;
 
+pub use self::dir::{write_bodies, write_manifest, ManifestEntry};
 pub use self::json::write_json;
-pub use self::raw::write_body;
+pub use self::raw::{dump_responses, write_body};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Error {
@@ -18,6 +18,7 @@
 use hurl_core::ast::SourceInfo;
 use hurl_core::error::Error;
 
+use crate::http::Response;
 use crate::runner::{HurlResult, RunnerError};
 use crate::util::logger::Logger;
 use crate::{output, runner};
@@ -47,25 +48,7 @@ pub fn write_body(
                 text.push('\n');
                 output.append(&mut text.into_bytes());
             }
-            let mut body = if entry_result.compressed {
-                match response.uncompress_body() {
-                    Ok(bytes) => bytes,
-                    Err(e) => {
-                        // FIXME: we convert to a runner::Error to be able to use fixme
-                        // method. Can we do otherwise (without creating an artificial
-                        // error a first character).
-                        let error = runner::Error {
-                            source_info: SourceInfo::new(0, 0, 0, 0),
-                            inner: RunnerError::from(e),
-                            assert: false,
-                        };
-                        let message = error.fixme();
-                        return Err(output::Error { message });
-                    }
-                }
-            } else {
-                response.body.clone()
-            };
+            let mut body = body_bytes(response, entry_result.compressed)?;
             output.append(&mut body);
             let result = output::write_output(&output, filename_out);
             if result.is_err() {
@@ -86,3 +69,39 @@ pub fn write_body(
     }
     Ok(())
 }
+
+/// Returns the last response of each entry of `hurl_result`, serialized in HTTP/1.1 wire format.
+///
+/// Contrary to [`write_body`], bodies are never uncompressed: they are written as they have been
+/// received, consistently with the response headers.
+pub fn dump_responses(hurl_result: &HurlResult) -> Vec<u8> {
+    let mut bytes = vec![];
+    for entry_result in hurl_result.entries.iter() {
+        if let Some(call) = entry_result.calls.last() {
+            bytes.append(&mut call.response.wire_bytes());
+        }
+    }
+    bytes
+}
+
+/// Returns the body bytes of `response`, uncompressed if `compressed` is true.
+pub(super) fn body_bytes(response: &Response, compressed: bool) -> Result<Vec<u8>, output::Error> {
+    if !compressed {
+        return Ok(response.body.clone());
+    }
+    match response.uncompress_body() {
+        Ok(bytes) => Ok(bytes),
+        Err(e) => {
+            // FIXME: we convert to a runner::Error to be able to use fixme
+            // method. Can we do otherwise (without creating an artificial
+            // error a first character).
+            let error = runner::Error {
+                source_info: SourceInfo::new(0, 0, 0, 0),
+                inner: RunnerError::from(e),
+                assert: false,
+            };
+            let message = error.fixme();
+            Err(output::Error { message })
+        }
+    }
+}
@@ -180,6 +180,9 @@ mod tests {
                 }],
                 time_in_ms: 0,
                 compressed: false,
+                slow: false,
+                retries: 0,
+                timestamp: chrono::Utc::now(),
             }],
             time_in_ms: 230,
             success: true,
@@ -204,6 +207,9 @@ mod tests {
                 }],
                 time_in_ms: 0,
                 compressed: false,
+                slow: false,
+                retries: 0,
+                timestamp: chrono::Utc::now(),
             }],
             time_in_ms: 230,
             success: true,
@@ -157,6 +157,9 @@ HTTP/1.0 200
                 }],
                 time_in_ms: 0,
                 compressed: false,
+                slow: false,
+                retries: 0,
+                timestamp: chrono::Utc::now(),
             }],
             time_in_ms: 230,
             success: true,
@@ -198,6 +201,9 @@ HTTP/1.0 200
                 }],
                 time_in_ms: 0,
                 compressed: false,
+                slow: false,
+                retries: 0,
+                timestamp: chrono::Utc::now(),
             }],
             time_in_ms: 230,
             success: true,
@@ -0,0 +1,52 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+//! Markdown report, to share the results of a run (in a pull request for instance).
+//!
+//! The report is a table with one row per entry of each Hurl file, followed by the details of
+//! the failed entries (assert errors and runtime errors).
+//!
+//! # Example:
+//!
+//! ````shell
+//! $ cat report.md
+//! # Hurl Report
+//!
+//! | File | Entry | Method | URL | Status | Duration | Result |
+//! |------|------:|--------|-----|-------:|---------:|--------|
+//! | tests/hello.hurl | 1 | GET | http://localhost:8000/hello | 200 | 12 ms | Passed |
+//! | tests/error_assert_status.hurl | 1 | GET | http://localhost:8000/not_found | 404 | 8 ms | Failed |
+//!
+//! ## Failures
+//!
+//! ### tests/error_assert_status.hurl, entry 1
+//!
+//! ```
+//! Assert status code
+//!   --> tests/error_assert_status.hurl:2:6
+//!    |
+//!  2 | HTTP 200
+//!    |      ^^^ actual value is <404>
+//!    |
+//! ```
+//! ````
+//!
+mod report;
+mod testcase;
+
+pub use self::report::write_report;
+pub use self::testcase::Testcase;
@@ -0,0 +1,148 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use std::fs::File;
+use std::io::Write;
+
+use super::Testcase;
+use crate::report::Error;
+
+/// Creates a Markdown report from a list of `testcases`. An existing report is overwritten.
+pub fn write_report(filename: &str, testcases: &[Testcase]) -> Result<(), Error> {
+    let mut file = match File::create(filename) {
+        Ok(f) => f,
+        Err(e) => {
+            return Err(Error {
+                message: format!("Failed to produce Markdown report: {e:?}"),
+            });
+        }
+    };
+    let s = create_markdown_report(testcases);
+    match file.write_all(s.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(Error {
+            message: format!("Failed to write Markdown report: {e:?}"),
+        }),
+    }
+}
+
+/// Returns a Markdown report from a list of `testcases`: a table of all the entries, followed by
+/// the failures details.
+fn create_markdown_report(testcases: &[Testcase]) -> String {
+    let mut s = "# Hurl Report\n\n".to_string();
+    s.push_str("| File | Entry | Method | URL | Status | Duration | Result |\n");
+    s.push_str("|------|------:|--------|-----|-------:|---------:|--------|\n");
+    for testcase in testcases {
+        s.push_str(&testcase.to_markdown_rows());
+    }
+    let failures = testcases
+        .iter()
+        .map(|t| t.to_markdown_failures())
+        .collect::<String>();
+    if !failures.is_empty() {
+        s.push_str("\n## Failures\n");
+        s.push_str(&failures);
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::markdown::testcase::Entry;
+
+    #[test]
+    fn test_create_markdown_report() {
+        let testcases = vec![
+            Testcase {
+                filename: "tests/hello.hurl".to_string(),
+                entries: vec![Entry {
+                    index: 1,
+                    method: "GET".to_string(),
+                    url: "http://localhost:8000/hello".to_string(),
+                    status: Some(200),
+                    time_in_ms: 12,
+                    failures: vec![],
+                }],
+            },
+            Testcase {
+                filename: "tests/error.hurl".to_string(),
+                entries: vec![
+                    Entry {
+                        index: 1,
+                        method: "GET".to_string(),
+                        url: "http://localhost:8000/not_found".to_string(),
+                        status: Some(404),
+                        time_in_ms: 8,
+                        failures: vec!["Assert status code\n  --> tests/error.hurl:2:6".to_string()],
+                    },
+                    Entry {
+                        index: 2,
+                        method: "-".to_string(),
+                        url: "-".to_string(),
+                        status: None,
+                        time_in_ms: 1,
+                        failures: vec!["HTTP connection\n  --> tests/error.hurl:4:5".to_string()],
+                    },
+                ],
+            },
+        ];
+        assert_eq!(
+            create_markdown_report(&testcases),
+            r#"# Hurl Report
+
+| File | Entry | Method | URL | Status | Duration | Result |
+|------|------:|--------|-----|-------:|---------:|--------|
+| tests/hello.hurl | 1 | GET | http://localhost:8000/hello | 200 | 12 ms | Passed |
+| tests/error.hurl | 1 | GET | http://localhost:8000/not_found | 404 | 8 ms | Failed |
+| tests/error.hurl | 2 | - | - | - | 1 ms | Failed |
+
+## Failures
+
+### tests/error.hurl, entry 1
+
+```
+Assert status code
+  --> tests/error.hurl:2:6
+```
+
+### tests/error.hurl, entry 2
+
+```
+HTTP connection
+  --> tests/error.hurl:4:5
+```
+"#
+        );
+    }
+
+    #[test]
+    fn test_create_markdown_report_without_failures() {
+        let testcases = vec![Testcase {
+            filename: "tests/hello.hurl".to_string(),
+            entries: vec![],
+        }];
+        assert_eq!(
+            create_markdown_report(&testcases),
+            r#"# Hurl Report
+
+| File | Entry | Method | URL | Status | Duration | Result |
+|------|------:|--------|-----|-------:|---------:|--------|
+"#
+        );
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::runner::HurlResult;
+use crate::util::logger;
+
+/// The Markdown view of a Hurl file run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Testcase {
+    pub(crate) filename: String,
+    pub(crate) entries: Vec<Entry>,
+}
+
+/// The result of an entry, the last HTTP call of the entry being the reported one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Entry {
+    pub(crate) index: usize,
+    pub(crate) method: String,
+    pub(crate) url: String,
+    pub(crate) status: Option<u32>,
+    pub(crate) time_in_ms: u128,
+    pub(crate) failures: Vec<String>,
+}
+
+impl Testcase {
+    /// Creates a Markdown testcase from an [`HurlResult`].
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &str) -> Testcase {
+        let mut entries = vec![];
+        let mut next_entries = hurl_result.entries.iter().skip(1);
+        for entry in hurl_result.entries.iter() {
+            // A retried entry is reported only once, with its last run.
+            if let Some(next) = next_entries.next() {
+                if next.entry_index == entry.entry_index {
+                    continue;
+                }
+            }
+            let call = entry.calls.last();
+            let failures = entry
+                .errors
+                .iter()
+                .map(|e| logger::error_string(filename, content, e, false))
+                .collect();
+            entries.push(Entry {
+                index: entry.entry_index,
+                method: call.map_or("-".to_string(), |c| c.request.method.clone()),
+                url: call.map_or("-".to_string(), |c| c.request.url.clone()),
+                status: call.map(|c| c.response.status),
+                time_in_ms: entry.time_in_ms,
+                failures,
+            });
+        }
+        Testcase {
+            filename: filename.to_string(),
+            entries,
+        }
+    }
+
+    /// Serializes the entries of this testcase to Markdown table rows.
+    pub(crate) fn to_markdown_rows(&self) -> String {
+        let mut s = String::new();
+        for entry in &self.entries {
+            let status = entry
+                .status
+                .map_or("-".to_string(), |status| status.to_string());
+            let result = if entry.failures.is_empty() {
+                "Passed"
+            } else {
+                "Failed"
+            };
+            s.push_str(&format!(
+                "| {} | {} | {} | {} | {status} | {} ms | {result} |\n",
+                escape(&self.filename),
+                entry.index,
+                escape(&entry.method),
+                escape(&entry.url),
+                entry.time_in_ms,
+            ));
+        }
+        s
+    }
+
+    /// Serializes the failures of the entries of this testcase to Markdown sections, each failure
+    /// message being a code block.
+    pub(crate) fn to_markdown_failures(&self) -> String {
+        let mut s = String::new();
+        for entry in self.entries.iter().filter(|e| !e.failures.is_empty()) {
+            s.push_str(&format!("\n### {}, entry {}\n", self.filename, entry.index));
+            for failure in &entry.failures {
+                s.push_str(&format!("\n```\n{failure}\n```\n"));
+            }
+        }
+        s
+    }
+}
+
+/// Escapes the pipe characters of a table cell `value`.
+fn escape(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_rows() {
+        let testcase = Testcase {
+            filename: "test.hurl".to_string(),
+            entries: vec![Entry {
+                index: 1,
+                method: "GET".to_string(),
+                url: "http://localhost:8000/query?a=1|2".to_string(),
+                status: Some(200),
+                time_in_ms: 5,
+                failures: vec![],
+            }],
+        };
+        assert_eq!(
+            testcase.to_markdown_rows(),
+            "| test.hurl | 1 | GET | http://localhost:8000/query?a=1\\|2 | 200 | 5 ms | Passed |\n"
+        );
+        assert_eq!(testcase.to_markdown_failures(), "");
+    }
+}
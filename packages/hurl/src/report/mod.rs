@@ -16,13 +16,14 @@
  *
  */
 
-//! Various reports for Hurl runs (JUnit, HTML etc...) A report aggregates multiple runs into
-//! a single unit.
+//! Various reports for Hurl runs (JUnit, HTML, Markdown etc...) A report aggregates multiple runs
+//! into a single unit.
 
 use std::fmt;
 
 pub mod html;
 pub mod junit;
+pub mod markdown;
 pub mod tap;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
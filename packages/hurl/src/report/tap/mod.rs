@@ -16,6 +16,34 @@
  *
  */
 
+//! TAP (Test Anything Protocol) version 13 report
+//!
+//! <https://testanything.org/tap-version-13-specification.html>
+//!
+//! One Hurl file will result into one TAP test point. A failed test point is followed
+//! by a YAML diagnostic block, listing the failures (assert errors) and errors (runtime errors).
+//!
+//! # Example:
+//!
+//! ```shell
+//! $ cat report.tap
+//! TAP version 13
+//! 1..2
+//! ok 1 - tests/hello.hurl
+//! not ok 2 - tests/error_assert_status.hurl
+//!   ---
+//!   severity: fail
+//!   failures:
+//!     - |
+//!       Assert status code
+//!         --> tests/error_assert_status.hurl:2:6
+//!          |
+//!        2 | HTTP 200
+//!          |      ^^^ actual value is <404>
+//!          |
+//!   ...
+//! ```
+//!
 mod report;
 mod testcase;
 
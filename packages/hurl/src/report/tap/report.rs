@@ -22,6 +22,8 @@ use super::Testcase;
 use std::fs::File;
 use std::io::Write;
 
+const TAP_VERSION: &str = "TAP version 13";
+
 /// Creates/Append a Tap report from a list of `testcases`
 pub fn write_report(filename: &str, new_testcases: &[Testcase]) -> Result<(), Error> {
     let mut testcases = vec![];
@@ -46,15 +48,7 @@ fn write_tap_file(filename: &str, testcases: &[&Testcase]) -> Result<(), Error>
             });
         }
     };
-    let start = 1;
-    let end = testcases.len();
-    let mut s = format!("{start}..{end}\n");
-    for (i, testcase) in testcases.iter().enumerate() {
-        let success = if testcase.success { "" } else { "not " };
-        let number = i + 1;
-        let description = &testcase.description;
-        s.push_str(format!("{success}ok {number} - {description}\n").as_str());
-    }
+    let s = create_tap_report(testcases);
     match file.write_all(s.as_bytes()) {
         Ok(_) => Ok(()),
         Err(e) => Err(Error {
@@ -63,6 +57,18 @@ fn write_tap_file(filename: &str, testcases: &[&Testcase]) -> Result<(), Error>
     }
 }
 
+/// Returns a TAP version 13 report from a list of `testcases`.
+fn create_tap_report(testcases: &[&Testcase]) -> String {
+    let start = 1;
+    let end = testcases.len();
+    let mut s = format!("{TAP_VERSION}\n{start}..{end}\n");
+    for (i, testcase) in testcases.iter().enumerate() {
+        let number = i + 1;
+        s.push_str(&testcase.to_tap(number));
+    }
+    s
+}
+
 /// Parse Tap report file
 fn parse_tap_file(filename: &str) -> Result<Vec<Testcase>, Error> {
     let path = std::path::Path::new(&filename);
@@ -83,8 +89,11 @@ fn parse_tap_file(filename: &str) -> Result<Vec<Testcase>, Error> {
 
 /// Parse Tap report
 fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, Error> {
-    let mut testcases = vec![];
+    let mut testcases: Vec<Testcase> = vec![];
     let mut lines: Vec<&str> = s.lines().collect::<Vec<&str>>();
+    if lines.first() == Some(&TAP_VERSION) {
+        lines.remove(0);
+    }
     if !lines.is_empty() {
         let header = lines.remove(0);
         let header_tokens = header.split("..").collect::<Vec<&str>>();
@@ -118,12 +127,25 @@ fn parse_tap_report(s: &str) -> Result<Vec<Testcase>, Error> {
                 }
             },
         };
-        for line in lines {
+        let mut lines = lines.into_iter();
+        while let Some(line) = lines.next() {
             let line = line.trim();
-            if !line.is_empty() {
-                let testcase = Testcase::parse(line)?;
-                testcases.push(testcase);
+            if line.is_empty() {
+                continue;
             }
+            if line == "---" {
+                // A YAML diagnostic block, related to the previous test point.
+                let diagnostics = lines
+                    .by_ref()
+                    .take_while(|l| l.trim() != "...")
+                    .collect::<Vec<_>>();
+                if let Some(testcase) = testcases.last_mut() {
+                    testcase.parse_diagnostics(&diagnostics);
+                }
+                continue;
+            }
+            let testcase = Testcase::parse(line)?;
+            testcases.push(testcase);
         }
     }
     Ok(testcases)
@@ -146,17 +168,77 @@ nok 3 - tests_ok/test.3.hurl
             vec![
                 Testcase {
                     description: "tests_ok/test.1.hurl".to_string(),
-                    success: true
+                    success: true,
+                    failures: vec![],
+                    errors: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.2.hurl".to_string(),
-                    success: true
+                    success: true,
+                    failures: vec![],
+                    errors: vec![],
                 },
                 Testcase {
                     description: "tests_ok/test.3.hurl".to_string(),
-                    success: false
+                    success: false,
+                    failures: vec![],
+                    errors: vec![],
                 }
             ]
         )
     }
+
+    #[test]
+    fn test_parse_tap_report_with_diagnostics() {
+        let s = r#"TAP version 13
+1..3
+ok 1 - tests_ok/test.1.hurl
+not ok 2 - tests_ok/test.2.hurl
+  ---
+  severity: fail
+  failures:
+    - |
+      Assert status code
+        --> tests_ok/test.2.hurl:2:6
+         |
+       2 | HTTP 200
+         |      ^^^ actual value is <404>
+         |
+  ...
+not ok 3 - tests_ok/test.3.hurl
+  ---
+  severity: fail
+  errors:
+    - |
+      HTTP connection
+        --> tests_ok/test.3.hurl:1:5
+         |
+       1 | GET http://unknown
+         |     ^^^^^^^^^^^^^^ (6) Could not resolve host: unknown
+         |
+  ...
+"#;
+        let testcases = parse_tap_report(s).unwrap();
+        assert_eq!(testcases.len(), 3);
+        assert!(testcases[0].success);
+        assert!(!testcases[1].success);
+        assert_eq!(
+            testcases[1].failures,
+            vec![r#"Assert status code
+  --> tests_ok/test.2.hurl:2:6
+   |
+ 2 | HTTP 200
+   |      ^^^ actual value is <404>
+   |"#
+            .to_string()]
+        );
+        assert!(testcases[1].errors.is_empty());
+        assert!(!testcases[2].success);
+        assert!(testcases[2].failures.is_empty());
+        assert_eq!(testcases[2].errors.len(), 1);
+
+        // The report is stable when written back.
+        let testcases = testcases.iter().collect::<Vec<_>>();
+        assert_eq!(create_tap_report(&testcases), s);
+    }
 }
@@ -18,38 +18,83 @@
 
 use crate::report::Error;
 use crate::runner::HurlResult;
+use crate::util::logger;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Testcase {
     pub(crate) description: String,
     pub(crate) success: bool,
+    pub(crate) failures: Vec<String>,
+    pub(crate) errors: Vec<String>,
 }
 
 impl Testcase {
     /// Creates an Tap &lt;testcase&gt; from an [`HurlResult`].
-    pub fn from(hurl_result: &HurlResult, filename: &str) -> Testcase {
+    pub fn from(hurl_result: &HurlResult, content: &str, filename: &str) -> Testcase {
         let description = filename.to_string();
-        let success = hurl_result.errors().is_empty();
+        let mut failures = vec![];
+        let mut errors = vec![];
+        for error in hurl_result.errors() {
+            let message = logger::error_string(filename, content, error, false);
+            if error.assert {
+                failures.push(message);
+            } else {
+                errors.push(message);
+            };
+        }
+        let success = failures.is_empty() && errors.is_empty();
         Testcase {
             description,
             success,
+            failures,
+            errors,
         }
     }
 
+    /// Serializes this testcase to a TAP test point with a test `number`.
+    /// A failed test point is followed by a YAML diagnostic block.
+    pub(crate) fn to_tap(&self, number: usize) -> String {
+        let success = if self.success { "" } else { "not " };
+        let description = &self.description;
+        let mut s = format!("{success}ok {number} - {description}\n");
+        if self.success {
+            return s;
+        }
+        s.push_str("  ---\n");
+        s.push_str("  severity: fail\n");
+        for (name, messages) in [("failures", &self.failures), ("errors", &self.errors)] {
+            if messages.is_empty() {
+                continue;
+            }
+            s.push_str(format!("  {name}:\n").as_str());
+            for message in messages {
+                s.push_str("    - |\n");
+                for line in message.lines() {
+                    s.push_str(format!("      {line}\n").as_str());
+                }
+            }
+        }
+        s.push_str("  ...\n");
+        s
+    }
+
     /// Creates an Tap &lt;testcase&gt; from a TAP line
     /// ok 1 - this is the first test
-    /// nok 2 - this is the second test
+    /// not ok 2 - this is the second test
     pub(crate) fn parse(line: &str) -> Result<Testcase, Error> {
         let mut line = line;
         let success = if line.starts_with("ok") {
             line = &line[2..];
             true
+        } else if line.starts_with("not ok") {
+            line = &line[6..];
+            false
         } else if line.starts_with("nok") {
             line = &line[3..];
             false
         } else {
             return Err(Error {
-                message: format!("Invalid TAP line <{line}> - must start with ok or nok"),
+                message: format!("Invalid TAP line <{line}> - must start with ok or not ok"),
             });
         };
 
@@ -71,8 +116,37 @@ impl Testcase {
         Ok(Testcase {
             description,
             success,
+            failures: vec![],
+            errors: vec![],
         })
     }
+
+    /// Updates the failures and errors of this testcase from the `lines` of a YAML diagnostic
+    /// block (without the `---` and `...` markers).
+    pub(crate) fn parse_diagnostics(&mut self, lines: &[&str]) {
+        let mut current: Option<&mut Vec<String>> = None;
+        let mut first_line = true;
+        for line in lines {
+            if let Some(text) = line.strip_prefix("      ") {
+                if let Some(message) = current.as_mut().and_then(|m| m.last_mut()) {
+                    if !first_line {
+                        message.push('\n');
+                    }
+                    message.push_str(text);
+                    first_line = false;
+                }
+            } else if line.trim() == "failures:" {
+                current = Some(&mut self.failures);
+            } else if line.trim() == "errors:" {
+                current = Some(&mut self.errors);
+            } else if line.trim() == "- |" {
+                if let Some(messages) = current.as_mut() {
+                    messages.push(String::new());
+                    first_line = true;
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -83,15 +157,49 @@ mod tests {
     fn parse_tap_test_line() {
         assert_eq!(
             Testcase::parse("toto").err().unwrap().message,
-            "Invalid TAP line <toto> - must start with ok or nok".to_string()
+            "Invalid TAP line <toto> - must start with ok or not ok".to_string()
         );
 
         assert_eq!(
             Testcase::parse("ok 1 - tests_ok/test.1.hurl").unwrap(),
             Testcase {
                 description: "tests_ok/test.1.hurl".to_string(),
-                success: true
+                success: true,
+                failures: vec![],
+                errors: vec![],
+            }
+        );
+
+        assert_eq!(
+            Testcase::parse("not ok 2 - tests_ok/test.2.hurl").unwrap(),
+            Testcase {
+                description: "tests_ok/test.2.hurl".to_string(),
+                success: false,
+                failures: vec![],
+                errors: vec![],
             }
         );
     }
+
+    #[test]
+    fn test_point_with_diagnostics() {
+        let testcase = Testcase {
+            description: "test.hurl".to_string(),
+            success: false,
+            failures: vec!["Assert status code\n  --> test.hurl:2:6".to_string()],
+            errors: vec![],
+        };
+        assert_eq!(
+            testcase.to_tap(2),
+            r#"not ok 2 - test.hurl
+  ---
+  severity: fail
+  failures:
+    - |
+      Assert status code
+        --> test.hurl:2:6
+  ...
+"#
+        );
+    }
 }
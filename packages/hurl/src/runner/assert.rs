@@ -132,32 +132,15 @@ pub fn eval_assert(
     assert: &Assert,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    retries: usize,
 ) -> AssertResult {
-    let query_result = eval_query(&assert.query, variables, http_response);
+    let query_result = eval_query(&assert.query, variables, http_response, retries);
 
     let actual = if assert.filters.is_empty() {
         query_result
     } else if let Ok(optional_value) = query_result {
-        match optional_value {
-            None => Err(Error {
-                source_info: assert
-                    .filters
-                    .first()
-                    .expect("at least one filter")
-                    .1
-                    .source_info
-                    .clone(),
-                inner: RunnerError::FilterMissingInput,
-                assert: true,
-            }),
-            Some(value) => {
-                let filters = assert.filters.iter().map(|(_, f)| f.clone()).collect();
-                match eval_filters(&filters, &value, variables, true) {
-                    Ok(value) => Ok(value),
-                    Err(e) => Err(e),
-                }
-            }
-        }
+        let filters = assert.filters.iter().map(|(_, f)| f.clone()).collect();
+        eval_filters(&filters, optional_value, variables, true)
     } else {
         query_result
     };
@@ -233,6 +216,7 @@ pub mod tests {
                 &assert_count_user(),
                 &variables,
                 &xml_three_users_http_response(),
+                0,
             ),
             AssertResult::Explicit {
                 actual: Ok(Some(Value::Integer(3))),
@@ -21,7 +21,7 @@ use hurl_core::ast::*;
 
 use crate::http;
 use crate::runner::core::{CaptureResult, Error, RunnerError};
-use crate::runner::filter::eval_filters;
+use crate::runner::filter::{accept_missing_input, eval_filters};
 use crate::runner::query::eval_query;
 use crate::runner::value::Value;
 
@@ -31,30 +31,29 @@ pub fn eval_capture(
     capture: &Capture,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    retries: usize,
 ) -> Result<CaptureResult, Error> {
     let name = &capture.name.value;
-    let value = eval_query(&capture.query, variables, http_response)?;
+    let value = eval_query(&capture.query, variables, http_response, retries)?;
+    let filters: Vec<Filter> = capture.filters.iter().map(|(_, f)| f.clone()).collect();
     let value = match value {
-        None => {
+        None if !accept_missing_input(&filters) => {
             return Err(Error {
                 source_info: capture.query.source_info.clone(),
                 inner: RunnerError::NoQueryResult,
                 assert: false,
             });
         }
-        Some(value) => {
-            let filters = capture.filters.iter().map(|(_, f)| f.clone()).collect();
-            match eval_filters(&filters, &value, variables, false)? {
-                None => {
-                    return Err(Error {
-                        source_info: capture.query.source_info.clone(),
-                        inner: RunnerError::NoQueryResult,
-                        assert: false,
-                    })
-                }
-                Some(v) => v,
+        value => match eval_filters(&filters, value, variables, false)? {
+            None => {
+                return Err(Error {
+                    source_info: capture.query.source_info.clone(),
+                    inner: RunnerError::NoQueryResult,
+                    assert: false,
+                })
             }
-        }
+            Some(v) => v,
+        },
     };
 
     Ok(CaptureResult {
@@ -156,9 +155,14 @@ pub mod tests {
             },
         };
 
-        let error = eval_capture(&capture, &variables, &http::xml_three_users_http_response())
-            .err()
-            .unwrap();
+        let error = eval_capture(
+            &capture,
+            &variables,
+            &http::xml_three_users_http_response(),
+            0,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 7 });
         assert_eq!(error.inner, RunnerError::QueryInvalidXpathEval)
     }
@@ -214,6 +218,7 @@ pub mod tests {
                 &user_count_capture(),
                 &variables,
                 &http::xml_three_users_http_response(),
+                0,
             )
             .unwrap(),
             CaptureResult {
@@ -223,7 +228,13 @@ pub mod tests {
         );
 
         assert_eq!(
-            eval_capture(&duration_capture(), &variables, &http::json_http_response()).unwrap(),
+            eval_capture(
+                &duration_capture(),
+                &variables,
+                &http::json_http_response(),
+                0
+            )
+            .unwrap(),
             CaptureResult {
                 name: "duration".to_string(),
                 value: Value::from_f64(1.5),
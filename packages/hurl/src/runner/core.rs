@@ -17,6 +17,8 @@
  */
 use std::path::PathBuf;
 
+use chrono::{DateTime, Utc};
+
 use hurl_core::ast::SourceInfo;
 
 use crate::http::{Call, Cookie};
@@ -61,6 +63,12 @@ pub struct EntryResult {
     pub errors: Vec<Error>,
     pub time_in_ms: u128,
     pub compressed: bool, // The entry has been executed with `--compressed` option
+    /// The entry took longer than the slow threshold (only a warning, not an error)
+    pub slow: bool,
+    /// The number of times the entry has been retried before this run (0 for a first run)
+    pub retries: usize,
+    /// The time at which this run of the entry has started
+    pub timestamp: DateTime<Utc>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -127,6 +135,9 @@ pub enum RunnerError {
     FailToConnect,
     Timeout,
     TooManyRedirect,
+    RedirectLoop {
+        urls: Vec<String>,
+    },
     CouldNotParseResponse,
     SslCertificate(String),
 
@@ -146,6 +157,10 @@ pub enum RunnerError {
     // Query
     QueryHeaderNotFound,
     QueryCookieNotFound,
+    QueryRedirectNotFound {
+        index: u64,
+        count: usize,
+    },
     QueryInvalidJsonpathExpression {
         value: String,
     },
@@ -193,4 +208,6 @@ pub enum RunnerError {
     FilterRegexNoCapture,
     FilterInvalidEncoding(String),
     FilterDecode(String),
+    FilterInvalidNormalizationForm(String),
+    FilterNoMatch,
 }
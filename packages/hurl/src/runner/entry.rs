@@ -18,6 +18,8 @@
 use std::collections::HashMap;
 use std::time::Duration;
 
+use chrono::Utc;
+
 use hurl_core::ast::*;
 
 use crate::http;
@@ -34,29 +36,46 @@ use crate::util::logger::{Logger, Verbosity};
 /// The `calls` field of the [`EntryResult`] contains a list of HTTP requests and responses that have
 /// been executed. If `http_client` has been configured to follow redirection, the `calls` list contains
 /// every step of the redirection for the first to the last.
-/// `variables` are used to render values at runtime, and can be updated by captures.
+/// `variables` are used to render values at runtime, and can be updated by captures. `retries` is the
+/// number of times this entry has already been run (see `--retry` option).
 pub fn run(
     entry: &Entry,
     entry_index: usize,
     http_client: &mut http::Client,
     variables: &mut HashMap<String, Value>,
     runner_options: &RunnerOptions,
+    retries: usize,
     logger: &Logger,
 ) -> EntryResult {
-    let http_request = match eval_request(&entry.request, variables, &runner_options.context_dir) {
-        Ok(r) => r,
-        Err(error) => {
-            return EntryResult {
-                entry_index,
-                calls: vec![],
-                captures: vec![],
-                asserts: vec![],
-                errors: vec![error],
-                time_in_ms: 0,
-                compressed: runner_options.compressed,
-            };
+    let timestamp = Utc::now();
+    let mut http_request =
+        match eval_request(&entry.request, variables, &runner_options.context_dir) {
+            Ok(r) => r,
+            Err(error) => {
+                return EntryResult {
+                    entry_index,
+                    calls: vec![],
+                    captures: vec![],
+                    asserts: vec![],
+                    errors: vec![error],
+                    time_in_ms: 0,
+                    compressed: runner_options.compressed,
+                    slow: false,
+                    retries,
+                    timestamp,
+                };
+            }
+        };
+    // The runner only accepts `--data-urlencode` for a single entry, whose method is kept as is.
+    if let Some(data) = &runner_options.data_urlencode {
+        if entry.request.body.is_none()
+            && http_request.form.is_empty()
+            && http_request.multipart.is_empty()
+        {
+            http_request.body = http::Body::Text(data.clone());
+            http_request.content_type = Some("application/x-www-form-urlencoded".to_string());
         }
-    };
+    }
     let client_options = ClientOptions::from(runner_options, logger.verbosity);
 
     // Experimental features with cookie storage
@@ -107,6 +126,9 @@ pub fn run(
                 errors: vec![error],
                 time_in_ms: 0,
                 compressed: client_options.compressed,
+                slow: false,
+                retries,
+                timestamp,
             };
         }
     };
@@ -114,12 +136,24 @@ pub fn run(
     // We runs capture and asserts on the last HTTP request/response chains.
     let call = calls.last().unwrap();
     let http_response = &call.response;
+    if let Some(content_length) = http_response.content_length() {
+        if http_response.transfer.size_excess > 0 {
+            logger.warning(&format!(
+                "Content-Length header <{content_length}> is less than the received body length <{}>, body has been truncated",
+                http_response.body_length()
+            ));
+        }
+    }
     // `time_in_ms` represent the network time of calls, not including assert processing.
     let time_in_ms = calls
         .iter()
         .map(|call| call.timings.total)
         .sum::<Duration>()
         .as_millis();
+    let slow = match runner_options.slow_threshold {
+        Some(threshold) => time_in_ms > threshold.as_millis(),
+        None => false,
+    };
 
     // We proceed asserts and captures in this order:
     // 1. first, check implicit assert on status and version. If KO, test is failed
@@ -141,6 +175,9 @@ pub fn run(
                     errors,
                     time_in_ms,
                     compressed: client_options.compressed,
+                    slow,
+                    retries,
+                    timestamp,
                 };
             }
             all_asserts.append(&mut asserts);
@@ -149,20 +186,25 @@ pub fn run(
 
     let captures = match &entry.response {
         None => vec![],
-        Some(response_spec) => match eval_captures(response_spec, http_response, variables) {
-            Ok(captures) => captures,
-            Err(e) => {
-                return EntryResult {
-                    entry_index,
-                    calls,
-                    captures: vec![],
-                    asserts: all_asserts,
-                    errors: vec![e],
-                    time_in_ms,
-                    compressed: client_options.compressed,
-                };
+        Some(response_spec) => {
+            match eval_captures(response_spec, http_response, variables, retries) {
+                Ok(captures) => captures,
+                Err(e) => {
+                    return EntryResult {
+                        entry_index,
+                        calls,
+                        captures: vec![],
+                        asserts: all_asserts,
+                        errors: vec![e],
+                        time_in_ms,
+                        compressed: client_options.compressed,
+                        slow,
+                        retries,
+                        timestamp,
+                    };
+                }
             }
-        },
+        }
     };
 
     if !captures.is_empty() {
@@ -181,6 +223,7 @@ pub fn run(
                 variables,
                 http_response,
                 &runner_options.context_dir,
+                retries,
             );
             all_asserts.append(&mut asserts);
         }
@@ -196,6 +239,9 @@ pub fn run(
         errors,
         time_in_ms,
         compressed: client_options.compressed,
+        slow,
+        retries,
+        timestamp,
     }
 }
 
@@ -229,6 +275,8 @@ impl ClientOptions {
             cookie_input_file: runner_options.cookie_input_file.clone(),
             path_as_is: runner_options.path_as_is,
             proxy: runner_options.proxy.clone(),
+            proxy_cacert_file: runner_options.proxy_cacert_file.clone(),
+            reload_ca_store: runner_options.reload_ca_store,
             no_proxy: runner_options.no_proxy.clone(),
             verbosity: match verbosity {
                 Some(Verbosity::Verbose) => Some(http::Verbosity::Verbose),
@@ -244,6 +292,8 @@ impl ClientOptions {
             user: runner_options.user.clone(),
             user_agent: runner_options.user_agent.clone(),
             compressed: runner_options.compressed,
+            verify_status: runner_options.verify_status,
+            websocket: runner_options.websocket,
         }
     }
 }
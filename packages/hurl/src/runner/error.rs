@@ -39,6 +39,7 @@ impl Error for runner::Error {
             RunnerError::FailToConnect => "HTTP connection".to_string(),
             RunnerError::Timeout => "HTTP connection".to_string(),
             RunnerError::TooManyRedirect => "HTTP connection".to_string(),
+            RunnerError::RedirectLoop { .. } => "HTTP connection".to_string(),
             RunnerError::CouldNotParseResponse => "HTTP connection".to_string(),
             RunnerError::SslCertificate { .. } => "SSL certificate".to_string(),
             RunnerError::PredicateValue { .. } => "Assert - predicate value failed".to_string(),
@@ -48,6 +49,7 @@ impl Error for runner::Error {
             RunnerError::QueryInvalidXpathEval => "Invalid XPath expression".to_string(),
             RunnerError::QueryHeaderNotFound => "Header not found".to_string(),
             RunnerError::QueryCookieNotFound => "Cookie not found".to_string(),
+            RunnerError::QueryRedirectNotFound { .. } => "Redirect not found".to_string(),
             RunnerError::AssertHeaderValueError { .. } => "Assert header value".to_string(),
             RunnerError::AssertBodyValueError { .. } => "Assert body value".to_string(),
             RunnerError::AssertVersion { .. } => "Assert HTTP version".to_string(),
@@ -69,6 +71,8 @@ impl Error for runner::Error {
             RunnerError::FilterRegexNoCapture => "Filter Error".to_string(),
             RunnerError::FilterInvalidEncoding { .. } => "Filter Error".to_string(),
             RunnerError::FilterDecode { .. } => "Filter Error".to_string(),
+            RunnerError::FilterInvalidNormalizationForm { .. } => "Filter Error".to_string(),
+            RunnerError::FilterNoMatch => "Filter Error".to_string(),
         }
     }
 
@@ -87,6 +91,9 @@ impl Error for runner::Error {
             RunnerError::FailToConnect => "fail to connect".to_string(),
             RunnerError::Timeout => "timeout has been reached".to_string(),
             RunnerError::TooManyRedirect => "too many redirect".to_string(),
+            RunnerError::RedirectLoop { urls } => {
+                format!("redirect loop detected: {}", urls.join(" -> "))
+            }
             RunnerError::CouldNotParseResponse => "could not parse response".to_string(),
             RunnerError::SslCertificate(description) => description.clone(),
             RunnerError::AssertVersion { actual, .. } => format!("actual value is <{actual}>"),
@@ -103,6 +110,9 @@ impl Error for runner::Error {
             RunnerError::QueryCookieNotFound => {
                 "this cookie has not been found in the response".to_string()
             }
+            RunnerError::QueryRedirectNotFound { index, count } => {
+                format!("redirect {index} is out of bound - {count} redirect(s) followed")
+            }
             RunnerError::QueryInvalidXpathEval => "the XPath expression is not valid".to_string(),
             RunnerError::AssertHeaderValueError { actual } => {
                 format!("actual value is <{actual}>")
@@ -166,6 +176,10 @@ impl Error for runner::Error {
             RunnerError::FilterDecode(encoding) => {
                 format!("value can not be decoded with <{encoding}> encoding")
             }
+            RunnerError::FilterInvalidNormalizationForm(form) => {
+                format!("<{form}> normalization form is not supported (NFC, NFD, NFKC or NFKD)")
+            }
+            RunnerError::FilterNoMatch => "no element matches the predicate".to_string(),
         }
     }
 }
@@ -203,11 +217,16 @@ impl From<HttpError> for RunnerError {
                 url,
             },
             HttpError::TooManyRedirect => RunnerError::TooManyRedirect,
+            HttpError::RedirectLoop { urls } => RunnerError::RedirectLoop { urls },
             HttpError::UnsupportedContentEncoding { description } => {
                 RunnerError::UnsupportedContentEncoding(description)
             }
             HttpError::InvalidUrl(url) => RunnerError::InvalidUrl(url),
             HttpError::InvalidUrlPrefix(url) => RunnerError::InvalidUrlPrefix(url),
+            HttpError::WebSocketHandshake { description, url } => RunnerError::HttpConnection {
+                message: format!("WebSocket handshake: {description}"),
+                url,
+            },
         }
     }
 }
@@ -101,6 +101,12 @@ pub fn run(
     } else {
         hurl_file.entries.len()
     };
+    // The `--data-urlencode` body is the body of a single request, it's not added to every entry.
+    if runner_options.data_urlencode.is_some() && n > 1 {
+        let message = "--data-urlencode can only be used with a Hurl file of a single entry";
+        logger.error(message);
+        return Err(message.to_string());
+    }
     let start = Instant::now();
 
     loop {
@@ -150,6 +156,7 @@ pub fn run(
                     &mut http_client,
                     &mut variables,
                     options,
+                    retry_count - 1,
                     &logger,
                 )
             }
@@ -161,6 +168,9 @@ pub fn run(
                 errors: vec![error.clone()],
                 time_in_ms: 0,
                 compressed: false,
+                slow: false,
+                retries: retry_count - 1,
+                timestamp: chrono::Utc::now(),
             },
         };
 
@@ -186,6 +196,10 @@ pub fn run(
             logger.debug("");
         }
 
+        if entry_result.slow {
+            warn_slow(entry, &entry_result, runner_options, &logger);
+        }
+
         let retry = !matches!(retry_opts, Retry::None) && !retry_max_reached && has_error;
         if has_error {
             log_errors(&entry_result, content, retry, &logger);
@@ -255,6 +269,27 @@ fn is_success(entries: &[EntryResult]) -> bool {
     true
 }
 
+/// Logs a warning for an entry that took longer than the slow threshold.
+fn warn_slow(
+    entry: &Entry,
+    entry_result: &EntryResult,
+    runner_options: &RunnerOptions,
+    logger: &Logger,
+) {
+    let Some(threshold) = runner_options.slow_threshold else {
+        return;
+    };
+    let filename = &logger.filename;
+    let source_info = &entry.request.space0.source_info;
+    let line = source_info.start.line;
+    let column = source_info.start.column;
+    let time = entry_result.time_in_ms;
+    let threshold = threshold.as_millis();
+    logger.warning(&format!(
+        "{filename}:{line}:{column} entry is slow: {time} ms (threshold {threshold} ms)"
+    ));
+}
+
 /// Logs deprecated syntax and provides alternatives.
 fn warn_deprecated(entry: &Entry, logger: &Logger) {
     // HTTP/* is used instead of HTTP.
@@ -384,7 +419,10 @@ fn log_run_info(
 
     if !variables.is_empty() {
         logger.debug_important("Variables:");
-        for (name, value) in variables.iter() {
+        // Variables are sorted by name so the dump is stable across runs.
+        let mut variables = variables.iter().collect::<Vec<_>>();
+        variables.sort_by_key(|(name, _)| *name);
+        for (name, value) in variables {
             logger.debug(format!("    {name}: {value}").as_str());
         }
     }
@@ -460,4 +498,19 @@ mod test {
         assert_eq!(first_non_default.0, "delay");
         assert_eq!(first_non_default.1, "500ms");
     }
+
+    #[test]
+    fn run_fails_with_data_urlencode_and_several_entries() {
+        let content = "GET http://localhost:8000/a\nGET http://localhost:8000/b\n";
+        let runner_options = RunnerOptionsBuilder::new()
+            .data_urlencode(Some("name=Bob".to_string()))
+            .build();
+        let logger_options = LoggerOptionsBuilder::new().build();
+        assert_eq!(
+            run(content, &runner_options, &HashMap::new(), &logger_options).err(),
+            Some(
+                "--data-urlencode can only be used with a Hurl file of a single entry".to_string()
+            )
+        );
+    }
 }
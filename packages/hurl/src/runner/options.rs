@@ -20,10 +20,10 @@ use crate::runner::template;
 use crate::runner::{Error, RunnerOptions, Value};
 use crate::util::logger::{Logger, Verbosity};
 use hurl_core::ast::{
-    Entry, EntryOption, Float, OptionKind, SectionValue, VariableDefinition, VariableValue,
+    DurationUnit, Entry, EntryOption, Float, OptionKind, SectionValue, VariableDefinition,
+    VariableValue,
 };
 use std::collections::HashMap;
-use std::time::Duration;
 
 /// Returns a new [`RunnerOptions`] based on the `entry` optional Options section
 /// and a default `runner_options`.
@@ -58,19 +58,27 @@ pub fn get_entry_options(
                     }
                     OptionKind::Compressed(value) => runner_options.compressed = *value,
                     OptionKind::ConnectTo(value) => runner_options.connects_to.push(value.clone()),
+                    OptionKind::ConnectTimeout(value) => {
+                        runner_options.connect_timeout = value.to_std(DurationUnit::Second)
+                    }
                     OptionKind::Delay(value) => {
-                        runner_options.delay = Duration::from_millis(*value)
+                        runner_options.delay = value.to_std(DurationUnit::MilliSecond)
                     }
                     OptionKind::Insecure(value) => runner_options.insecure = *value,
                     OptionKind::FollowLocation(value) => runner_options.follow_location = *value,
                     OptionKind::MaxRedirect(value) => runner_options.max_redirect = Some(*value),
+                    OptionKind::MaxTime(value) => {
+                        runner_options.timeout = value.to_std(DurationUnit::Second)
+                    }
                     OptionKind::PathAsIs(value) => runner_options.path_as_is = *value,
                     OptionKind::Proxy(value) => runner_options.proxy = Some(value.clone()),
                     OptionKind::Resolve(value) => runner_options.resolves.push(value.clone()),
                     OptionKind::Retry(value) => runner_options.retry = *value,
                     OptionKind::RetryInterval(value) => {
-                        runner_options.retry_interval = Duration::from_millis(*value)
+                        runner_options.retry_interval = value.to_std(DurationUnit::MilliSecond)
                     }
+                    OptionKind::VerifyStatus(value) => runner_options.verify_status = *value,
+                    OptionKind::WebSocket(value) => runner_options.websocket = *value,
                     OptionKind::Variable(VariableDefinition { name, value, .. }) => {
                         let value = eval_variable_value(value, variables)?;
                         variables.insert(name.clone(), value);
@@ -212,6 +212,15 @@ fn expected_no_value(
             let expected = eval_predicate_value_template(expected, variables)?;
             Ok(format!("matches regex <{expected}>"))
         }
+        PredicateFuncValue::OneOf { values } => {
+            let values = eval_one_of_values(values, variables)?;
+            let values = values.iter().map(|v| v.expected()).collect::<Vec<_>>();
+            Ok(format!("one of <{}>", values.join(", ")))
+        }
+        PredicateFuncValue::HasExactlyKeys { keys } => {
+            let keys = eval_keys(keys, variables)?;
+            Ok(format!("exactly keys <{}>", keys.join(", ")))
+        }
         PredicateFuncValue::IsInteger => Ok("integer".to_string()),
         PredicateFuncValue::IsFloat => Ok("float".to_string()),
         PredicateFuncValue::IsBoolean => Ok("boolean".to_string()),
@@ -220,6 +229,8 @@ fn expected_no_value(
         PredicateFuncValue::IsDate => Ok("date".to_string()),
         PredicateFuncValue::Exist => Ok("something".to_string()),
         PredicateFuncValue::IsEmpty => Ok("empty".to_string()),
+        PredicateFuncValue::IsSorted => Ok("sorted in ascending order".to_string()),
+        PredicateFuncValue::IsSortedDescending => Ok("sorted in descending order".to_string()),
     }
 }
 
@@ -278,6 +289,10 @@ fn eval_predicate_func(
         PredicateFuncValue::Match {
             value: expected, ..
         } => eval_match(expected, &predicate_func.source_info, variables, value),
+        PredicateFuncValue::OneOf { values } => eval_one_of(values, variables, value),
+        PredicateFuncValue::HasExactlyKeys { keys } => {
+            eval_has_exactly_keys(keys, variables, value)
+        }
         PredicateFuncValue::IsInteger => eval_is_integer(value),
         PredicateFuncValue::IsFloat => eval_is_float(value),
         PredicateFuncValue::IsBoolean => eval_is_boolean(value),
@@ -286,6 +301,8 @@ fn eval_predicate_func(
         PredicateFuncValue::IsDate => eval_is_date(value),
         PredicateFuncValue::Exist => eval_exist(value),
         PredicateFuncValue::IsEmpty => eval_is_empty(value),
+        PredicateFuncValue::IsSorted => eval_is_sorted(value, false),
+        PredicateFuncValue::IsSortedDescending => eval_is_sorted(value, true),
     }
 }
 
@@ -498,6 +515,89 @@ fn eval_match(
     }
 }
 
+/// Evaluates if an `actual` value is equal to one of the `expected` values (using a `variables` set).
+fn eval_one_of(
+    expected: &[(Whitespace, PredicateValue)],
+    variables: &HashMap<String, Value>,
+    actual: &Value,
+) -> Result<AssertResult, Error> {
+    let expected = eval_one_of_values(expected, variables)?;
+    let success = expected
+        .iter()
+        .any(|value| assert_values_equal(actual, value).success);
+    let expected = expected.iter().map(|v| v.display()).collect::<Vec<_>>();
+    Ok(AssertResult {
+        success,
+        actual: actual.display(),
+        expected: format!("one of <{}>", expected.join(", ")),
+        type_mismatch: false,
+    })
+}
+
+fn eval_one_of_values(
+    values: &[(Whitespace, PredicateValue)],
+    variables: &HashMap<String, Value>,
+) -> Result<Vec<Value>, Error> {
+    values
+        .iter()
+        .map(|(_, value)| eval_predicate_value(value, variables))
+        .collect()
+}
+
+/// Evaluates if an `actual` object has exactly the `expected` keys (using a `variables` set).
+///
+/// On failure, the unexpected and the missing keys are listed in the actual value.
+fn eval_has_exactly_keys(
+    expected: &[(Whitespace, Template)],
+    variables: &HashMap<String, Value>,
+    actual: &Value,
+) -> Result<AssertResult, Error> {
+    let expected = eval_keys(expected, variables)?;
+    let expected_display = format!("exactly keys <{}>", expected.join(", "));
+    let Value::Object(entries) = actual else {
+        return Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected: expected_display,
+            type_mismatch: true,
+        });
+    };
+    let keys = entries.iter().map(|(key, _)| key).collect::<Vec<_>>();
+    let unexpected = keys
+        .iter()
+        .filter(|key| !expected.contains(key))
+        .map(|key| key.as_str())
+        .collect::<Vec<_>>();
+    let missing = expected
+        .iter()
+        .filter(|key| !keys.contains(key))
+        .map(|key| key.as_str())
+        .collect::<Vec<_>>();
+    let keys = keys.iter().map(|key| key.as_str()).collect::<Vec<_>>();
+    let mut actual = format!("keys <{}>", keys.join(", "));
+    if !unexpected.is_empty() {
+        actual.push_str(&format!(", unexpected <{}>", unexpected.join(", ")));
+    }
+    if !missing.is_empty() {
+        actual.push_str(&format!(", missing <{}>", missing.join(", ")));
+    }
+    Ok(AssertResult {
+        success: unexpected.is_empty() && missing.is_empty(),
+        actual,
+        expected: expected_display,
+        type_mismatch: false,
+    })
+}
+
+fn eval_keys(
+    keys: &[(Whitespace, Template)],
+    variables: &HashMap<String, Value>,
+) -> Result<Vec<String>, Error> {
+    keys.iter()
+        .map(|(_, key)| eval_template(key, variables))
+        .collect()
+}
+
 /// Evaluates if an `actual` value is an integer.
 fn eval_is_integer(actual: &Value) -> Result<AssertResult, Error> {
     Ok(AssertResult {
@@ -624,6 +724,49 @@ fn eval_is_empty(actual: &Value) -> Result<AssertResult, Error> {
     }
 }
 
+/// Evaluates if an `actual` list is sorted, in ascending order or in `descending` order.
+/// Consecutive equal items are allowed, and items must be all numbers, all strings or all dates.
+fn eval_is_sorted(actual: &Value, descending: bool) -> Result<AssertResult, Error> {
+    let expected = if descending {
+        "sorted in descending order".to_string()
+    } else {
+        "sorted in ascending order".to_string()
+    };
+    let Value::List(values) = actual else {
+        return Ok(AssertResult {
+            success: false,
+            actual: actual.display(),
+            expected,
+            type_mismatch: true,
+        });
+    };
+    let mut success = true;
+    for pair in values.windows(2) {
+        match compare_values(&pair[0], &pair[1]) {
+            Some(order) => {
+                if (descending && order < 0) || (!descending && order > 0) {
+                    success = false;
+                }
+            }
+            // Items of different types can not be ordered.
+            None => {
+                return Ok(AssertResult {
+                    success: false,
+                    actual: actual.display(),
+                    expected,
+                    type_mismatch: true,
+                })
+            }
+        }
+    }
+    Ok(AssertResult {
+        success,
+        actual: actual.display(),
+        expected,
+        type_mismatch: false,
+    })
+}
+
 fn assert_values_equal(actual: &Value, expected: &Value) -> AssertResult {
     let actual_display = actual.display();
     let expected_display = expected.display();
@@ -887,6 +1030,11 @@ fn compare_values(actual: &Value, expected: &Value) -> Option<i32> {
         (Value::Float(f1), Value::Integer(i2)) => Some(compare_float(*f1, *i2 as f64)),
         (Value::Integer(i1), Value::Float(f2)) => Some(compare_float(*i1 as f64, *f2)),
         (Value::String(s1), Value::String(s2)) => Some(compare_string(s1, s2)),
+        (Value::Date(d1), Value::Date(d2)) => Some(match d1.cmp(d2) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }),
         _ => None,
     }
 }
@@ -1473,6 +1621,75 @@ mod tests {
         assert_eq!(assert_result.expected.as_str(), "count equals to 0");
     }
 
+    #[test]
+    fn test_predicate_is_sorted() {
+        // predicate: `isSorted`
+        // value: [1, 2.5, 2.5, 10]
+        let value = Value::List(vec![
+            Value::Integer(1),
+            Value::Float(2.5),
+            Value::Float(2.5),
+            Value::Integer(10),
+        ]);
+        let assert_result = eval_is_sorted(&value, false).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.expected.as_str(), "sorted in ascending order");
+
+        // predicate: `isSorted`
+        // value: ["b", "c", "a"]
+        let value = Value::List(vec![
+            Value::String("b".to_string()),
+            Value::String("c".to_string()),
+            Value::String("a".to_string()),
+        ]);
+        let assert_result = eval_is_sorted(&value, false).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+
+        // predicate: `isSortedDescending`
+        // value: [2023-01-02, 2023-01-01]
+        let value = Value::List(vec![
+            Value::Date(
+                chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2023, 1, 2, 0, 0, 0).unwrap(),
+            ),
+            Value::Date(
+                chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2023, 1, 1, 0, 0, 0).unwrap(),
+            ),
+        ]);
+        let assert_result = eval_is_sorted(&value, true).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.expected.as_str(),
+            "sorted in descending order"
+        );
+        let assert_result = eval_is_sorted(&value, false).unwrap();
+        assert!(!assert_result.success);
+
+        // predicate: `isSorted`
+        // value: []
+        let assert_result = eval_is_sorted(&Value::List(vec![]), false).unwrap();
+        assert!(assert_result.success);
+    }
+
+    #[test]
+    fn test_predicate_is_sorted_type_mismatch() {
+        // predicate: `isSorted`
+        // value: [1, "a"]
+        let value = Value::List(vec![Value::Integer(1), Value::String("a".to_string())]);
+        let assert_result = eval_is_sorted(&value, false).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+
+        // predicate: `isSorted`
+        // value: 1
+        let assert_result = eval_is_sorted(&Value::Integer(1), false).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "int <1>");
+    }
+
     #[test]
     fn test_predicate_type() {
         // predicate: `isInteger`
@@ -1636,4 +1853,86 @@ mod tests {
         assert_eq!(assert_result.actual.as_str(), "string <aa>");
         assert_eq!(assert_result.expected.as_str(), "matches regex <a{3}>");
     }
+
+    #[test]
+    fn test_predicate_one_of() {
+        let variables = HashMap::new();
+
+        // predicate: `oneOf 200 204`
+        let expected = vec![
+            (whitespace(), PredicateValue::Integer(200)),
+            (whitespace(), PredicateValue::Integer(204)),
+        ];
+        let value = Value::Integer(204);
+        let assert_result = eval_one_of(&expected, &variables, &value).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "int <204>");
+        assert_eq!(
+            assert_result.expected.as_str(),
+            "one of <int <200>, int <204>>"
+        );
+
+        let value = Value::Integer(500);
+        let assert_result = eval_one_of(&expected, &variables, &value).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "int <500>");
+    }
+
+    #[test]
+    fn test_predicate_has_exactly_keys() {
+        let variables = HashMap::new();
+        let key = |value: &str| {
+            (
+                whitespace(),
+                Template {
+                    delimiter: Some('"'),
+                    elements: vec![TemplateElement::String {
+                        value: value.to_string(),
+                        encoded: value.to_string(),
+                    }],
+                    source_info: SourceInfo::new(0, 0, 0, 0),
+                },
+            )
+        };
+        let object = |keys: &[&str]| {
+            Value::Object(
+                keys.iter()
+                    .map(|key| (key.to_string(), Value::Integer(1)))
+                    .collect(),
+            )
+        };
+
+        // predicate: `hasExactlyKeys "id" "name"`
+        let expected = vec![key("id"), key("name")];
+        let assert_result =
+            eval_has_exactly_keys(&expected, &variables, &object(&["name", "id"])).unwrap();
+        assert!(assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(assert_result.actual.as_str(), "keys <name, id>");
+        assert_eq!(assert_result.expected.as_str(), "exactly keys <id, name>");
+
+        let assert_result =
+            eval_has_exactly_keys(&expected, &variables, &object(&["id", "name", "role"])).unwrap();
+        assert!(!assert_result.success);
+        assert!(!assert_result.type_mismatch);
+        assert_eq!(
+            assert_result.actual.as_str(),
+            "keys <id, name, role>, unexpected <role>"
+        );
+
+        let assert_result =
+            eval_has_exactly_keys(&expected, &variables, &object(&["id", "email"])).unwrap();
+        assert!(!assert_result.success);
+        assert_eq!(
+            assert_result.actual.as_str(),
+            "keys <id, email>, unexpected <email>, missing <name>"
+        );
+
+        let assert_result =
+            eval_has_exactly_keys(&expected, &variables, &Value::List(vec![])).unwrap();
+        assert!(!assert_result.success);
+        assert!(assert_result.type_mismatch);
+    }
 }
@@ -17,6 +17,7 @@
  */
 use std::collections::HashMap;
 
+use chrono::Utc;
 use hurl_core::ast::*;
 use regex::Regex;
 use sha2::Digest;
@@ -31,20 +32,41 @@ use crate::{http, jsonpath};
 pub type QueryResult = Result<Option<Value>, Error>;
 
 /// Evaluates this `query` and returns a [`QueryResult`], using the HTTP response `http_response` and `variables`.
+/// `retries` is the number of times the entry has been retried before this run.
 pub fn eval_query(
     query: &Query,
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
+    retries: usize,
 ) -> QueryResult {
     match query.value.clone() {
         QueryValue::Status => eval_query_status(http_response),
+        QueryValue::ReasonPhrase => eval_query_reason_phrase(http_response),
         QueryValue::Url => eval_query_url(http_response),
         QueryValue::Header { name, .. } => eval_query_header(http_response, &name, variables),
+        QueryValue::PseudoHeader { name, .. } => {
+            eval_query_pseudo_header(http_response, &name, variables)
+        }
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
         } => eval_query_cookie(http_response, &name, &attribute, variables),
+        QueryValue::CookieNth {
+            index, attribute, ..
+        } => eval_query_cookie_nth(http_response, index, &attribute),
         QueryValue::Body => eval_query_body(http_response, &query.source_info),
+        QueryValue::BodyLength => Ok(Some(Value::Integer(http_response.body_length() as i64))),
+        QueryValue::CompressionRatio => {
+            eval_query_compression_ratio(http_response, &query.source_info)
+        }
+        QueryValue::ContentLength => Ok(http_response
+            .content_length()
+            .map(|length| Value::Integer(length as i64))),
+        QueryValue::ContentType => Ok(http_response.media_type().map(Value::String)),
+        QueryValue::FreshnessLifetime => Ok(http_response
+            .freshness_lifetime()
+            .map(|lifetime| Value::Integer(lifetime as i64))),
+        QueryValue::IsFresh => Ok(Some(Value::Bool(http_response.is_fresh(Utc::now())))),
         QueryValue::Xpath { expr, .. } => {
             eval_query_xpath(http_response, &expr, variables, &query.source_info)
         }
@@ -56,6 +78,7 @@ pub fn eval_query(
         }
         QueryValue::Variable { name, .. } => eval_query_variable(&name, variables),
         QueryValue::Duration => eval_query_duration(http_response),
+        QueryValue::Retries => Ok(Some(Value::Integer(retries as i64))),
         QueryValue::Bytes => eval_query_bytes(http_response, &query.source_info),
         QueryValue::Sha256 => eval_query_sha256(http_response, &query.source_info),
         QueryValue::Md5 => eval_query_md5(http_response, &query.source_info),
@@ -63,6 +86,25 @@ pub fn eval_query(
             attribute_name: field,
             ..
         } => eval_query_certificate(http_response, field),
+        QueryValue::TlsResumed => Ok(http_response.tls_resumed.map(Value::Bool)),
+        QueryValue::Transfer {
+            attribute_name: field,
+            ..
+        } => eval_query_transfer(http_response, field),
+        QueryValue::Redirects => eval_query_redirects(http_response),
+        QueryValue::Redirect {
+            index, attribute, ..
+        } => eval_query_redirect(
+            http_response,
+            index,
+            &attribute,
+            variables,
+            &query.source_info,
+        ),
+        QueryValue::Request {
+            attribute_name: field,
+            ..
+        } => Ok(eval_query_request(http_response, field)),
     }
 }
 
@@ -70,6 +112,10 @@ fn eval_query_status(response: &http::Response) -> QueryResult {
     Ok(Some(Value::Integer(i64::from(response.status))))
 }
 
+fn eval_query_reason_phrase(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::String(response.reason.clone())))
+}
+
 fn eval_query_url(response: &http::Response) -> QueryResult {
     Ok(Some(Value::String(response.url.clone())))
 }
@@ -95,6 +141,15 @@ fn eval_query_header(
     }
 }
 
+fn eval_query_pseudo_header(
+    response: &http::Response,
+    name: &Template,
+    variables: &HashMap<String, Value>,
+) -> QueryResult {
+    let name = eval_template(name, variables)?;
+    Ok(response.get_pseudo_header(&name).map(Value::String))
+}
+
 fn eval_query_cookie(
     response: &http::Response,
     name: &Template,
@@ -115,6 +170,23 @@ fn eval_query_cookie(
     }
 }
 
+fn eval_query_cookie_nth(
+    response: &http::Response,
+    index: u64,
+    attribute: &CookieNthAttribute,
+) -> QueryResult {
+    match response.cookies().into_iter().nth(index as usize) {
+        None => Ok(None),
+        Some(cookie) => {
+            let value = match attribute {
+                CookieNthAttribute::Name => cookie.name,
+                CookieNthAttribute::Value => cookie.value,
+            };
+            Ok(Some(Value::String(value)))
+        }
+    }
+}
+
 fn eval_query_body(response: &http::Response, query_source_info: &SourceInfo) -> QueryResult {
     // Can return a string if encoding is known and utf8.
     match response.text() {
@@ -278,6 +350,30 @@ fn eval_query_sha256(response: &http::Response, query_source_info: &SourceInfo)
     Ok(Some(bytes))
 }
 
+/// Returns the part of the uncompressed body size saved by the response compression, 0 for an
+/// uncompressed response.
+fn eval_query_compression_ratio(
+    response: &http::Response,
+    query_source_info: &SourceInfo,
+) -> QueryResult {
+    let uncompressed = match response.uncompress_body() {
+        Ok(s) => s,
+        Err(inner) => {
+            return Err(Error {
+                source_info: query_source_info.clone(),
+                inner: RunnerError::from(inner),
+                assert: false,
+            })
+        }
+    };
+    let ratio = if uncompressed.is_empty() {
+        0.0
+    } else {
+        1.0 - response.body.len() as f64 / uncompressed.len() as f64
+    };
+    Ok(Some(Value::Float(ratio)))
+}
+
 fn eval_query_md5(response: &http::Response, query_source_info: &SourceInfo) -> QueryResult {
     let bytes = match response.uncompress_body() {
         Ok(s) => s,
@@ -306,6 +402,19 @@ fn eval_query_certificate(
             CertificateAttributeName::SerialNumber => {
                 Value::String(certificate.serial_number.clone())
             }
+            CertificateAttributeName::ChainLength => {
+                Value::Integer(certificate.chain_length as i64)
+            }
+            CertificateAttributeName::SelfSigned => Value::Bool(certificate.is_self_signed()),
+            // libcurl only accepts a "good" OCSP status, any other status fails the transfer.
+            CertificateAttributeName::OcspStatus if certificate.ocsp_verified => {
+                Value::String("good".to_string())
+            }
+            CertificateAttributeName::OcspStatus => return Ok(None),
+            CertificateAttributeName::Pem => match &certificate.pem {
+                Some(pem) => Value::String(pem.clone()),
+                None => return Ok(None),
+            },
         };
         Ok(Some(value))
     } else {
@@ -313,6 +422,81 @@ fn eval_query_certificate(
     }
 }
 
+fn eval_query_transfer(
+    response: &http::Response,
+    transfer_attribute: TransferAttributeName,
+) -> QueryResult {
+    let transfer = &response.transfer;
+    let value = match transfer_attribute {
+        TransferAttributeName::SizeDownload => transfer.size_download,
+        TransferAttributeName::SpeedDownload => transfer.speed_download,
+        TransferAttributeName::SpeedUpload => transfer.speed_upload,
+    };
+    Ok(Some(Value::Integer(value as i64)))
+}
+
+/// Returns an attribute of the request sent for this `response`: its HTTP version (`HTTP/1.1`,
+/// `HTTP/2` etc...) or its request line (`GET /path HTTP/2` for instance).
+fn eval_query_request(
+    response: &http::Response,
+    request_attribute: RequestAttributeName,
+) -> Option<Value> {
+    let line = &response.request_line;
+    if line.is_empty() {
+        return None;
+    }
+    let value = match request_attribute {
+        RequestAttributeName::Version => line.split_whitespace().last()?.to_string(),
+        RequestAttributeName::Line => line.clone(),
+    };
+    Some(Value::String(value))
+}
+
+fn eval_query_redirects(response: &http::Response) -> QueryResult {
+    Ok(Some(Value::Integer(response.redirects.len() as i64)))
+}
+
+fn eval_query_redirect(
+    response: &http::Response,
+    index: u64,
+    redirect_attribute: &RedirectAttribute,
+    variables: &HashMap<String, Value>,
+    query_source_info: &SourceInfo,
+) -> QueryResult {
+    let redirect = match response.redirects.get(index as usize) {
+        Some(redirect) => redirect,
+        None => {
+            return Err(Error {
+                source_info: query_source_info.clone(),
+                inner: RunnerError::QueryRedirectNotFound {
+                    index,
+                    count: response.redirects.len(),
+                },
+                assert: false,
+            })
+        }
+    };
+    match redirect_attribute {
+        RedirectAttribute::Status => Ok(Some(Value::Integer(i64::from(redirect.status)))),
+        RedirectAttribute::Header { name, .. } => {
+            let name = eval_template(name, variables)?;
+            let values = redirect.get_header_values(&name);
+            if values.is_empty() {
+                Ok(None)
+            } else if values.len() == 1 {
+                let value = values.first().unwrap().to_string();
+                Ok(Some(Value::String(value)))
+            } else {
+                let values = values
+                    .iter()
+                    .map(|v| Value::String(v.to_string()))
+                    .collect();
+                Ok(Some(Value::List(values)))
+            }
+        }
+    }
+}
+
 fn eval_cookie_attribute_name(
     cookie_attribute_name: CookieAttributeName,
     cookie: http::ResponseCookie,
@@ -320,11 +504,10 @@ fn eval_cookie_attribute_name(
     match cookie_attribute_name {
         CookieAttributeName::Value(_) => Some(Value::String(cookie.value)),
         CookieAttributeName::Expires(_) => {
-            let s = cookie.expires().unwrap();
-            match chrono::DateTime::parse_from_rfc2822(s.as_str()) {
-                Ok(v) => Some(Value::Date(v.with_timezone(&chrono::Utc))),
-                Err(_) => todo!(),
-            }
+            // An Expires attribute that is not a valid date is ignored.
+            let s = cookie.expires()?;
+            let date = chrono::DateTime::parse_from_rfc2822(s.as_str()).ok()?;
+            Some(Value::Date(date.with_timezone(&chrono::Utc)))
         }
         CookieAttributeName::MaxAge(_) => cookie.max_age().map(Value::Integer),
         CookieAttributeName::Domain(_) => cookie.domain().map(Value::String),
@@ -377,6 +560,8 @@ impl Value {
 
 #[cfg(test)]
 pub mod tests {
+    use std::io::Write;
+
     use hex_literal::hex;
 
     use hurl_core::ast::{Pos, SourceInfo};
@@ -563,6 +748,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                0,
             )
             .unwrap()
             .unwrap(),
@@ -570,6 +756,27 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_retries() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(0, 0, 0, 0),
+            value: QueryValue::Retries,
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &http::hello_http_response(), 0)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(0)
+        );
+        assert_eq!(
+            eval_query(&query, &variables, &http::hello_http_response(), 2)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(2)
+        );
+    }
+
     #[test]
     fn test_header_not_found() {
         let variables = HashMap::new();
@@ -595,7 +802,7 @@ pub mod tests {
         //    assert_eq!(error.source_info.start, Pos { line: 1, column: 8 });
         //    assert_eq!(error.inner, RunnerError::QueryHeaderNotFound);
         assert_eq!(
-            eval_query(&query_header, &variables, &http::hello_http_response()).unwrap(),
+            eval_query(&query_header, &variables, &http::hello_http_response(), 0).unwrap(),
             None
         );
     }
@@ -622,7 +829,7 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query_header, &variables, &http::hello_http_response())
+            eval_query(&query_header, &variables, &http::hello_http_response(), 0)
                 .unwrap()
                 .unwrap(),
             Value::String(String::from("text/html; charset=utf-8"))
@@ -662,7 +869,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
             Value::String("DQAAAKEaem_vYg".to_string())
         );
 
@@ -689,7 +898,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
             Value::String("/accounts".to_string())
         );
 
@@ -716,7 +927,9 @@ pub mod tests {
             },
         };
         assert_eq!(
-            eval_query(&query, &variables, &response).unwrap().unwrap(),
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
             Value::Unit
         );
 
@@ -742,7 +955,71 @@ pub mod tests {
                 },
             },
         };
-        assert_eq!(eval_query(&query, &variables, &response).unwrap(), None);
+        assert_eq!(eval_query(&query, &variables, &response, 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_query_cookie_nth() {
+        let variables = HashMap::new();
+        let space = Whitespace {
+            value: String::new(),
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let response = http::Response {
+            headers: vec![
+                http::Header::new("Set-Cookie", "cookie1=value1; Path=/"),
+                http::Header::new("Content-Type", "text/html"),
+                http::Header::new("Set-Cookie", "cookie2=value2; HttpOnly"),
+                http::Header::new("Set-Cookie", "cookie3=value3"),
+            ],
+            ..Default::default()
+        };
+        let query = |index: u64, attribute: CookieNthAttribute| Query {
+            source_info: SourceInfo::new(0, 0, 0, 0),
+            value: QueryValue::CookieNth {
+                space0: space.clone(),
+                space1: space.clone(),
+                index,
+                space2: space.clone(),
+                attribute,
+            },
+        };
+
+        // cookie nth 1 name
+        assert_eq!(
+            eval_query(
+                &query(1, CookieNthAttribute::Name),
+                &variables,
+                &response,
+                0
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("cookie2".to_string())
+        );
+        // cookie nth 1 value
+        assert_eq!(
+            eval_query(
+                &query(1, CookieNthAttribute::Value),
+                &variables,
+                &response,
+                0
+            )
+            .unwrap()
+            .unwrap(),
+            Value::String("value2".to_string())
+        );
+        // cookie nth 3 name
+        assert_eq!(
+            eval_query(
+                &query(3, CookieNthAttribute::Name),
+                &variables,
+                &response,
+                0
+            )
+            .unwrap(),
+            None
+        );
     }
 
     #[test]
@@ -827,6 +1104,30 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_eval_cookie_attribute_name_session_cookie() {
+        let cookie = http::ResponseCookie::parse("SID=31d4d96e407aad42".to_string()).unwrap();
+        for name in [
+            CookieAttributeName::Expires("_".to_string()),
+            CookieAttributeName::MaxAge("_".to_string()),
+            CookieAttributeName::Domain("_".to_string()),
+            CookieAttributeName::Path("_".to_string()),
+        ] {
+            assert_eq!(eval_cookie_attribute_name(name, cookie.clone()), None);
+        }
+    }
+
+    #[test]
+    fn test_eval_cookie_attribute_name_invalid_expires() {
+        let cookie =
+            http::ResponseCookie::parse("SID=31d4d96e407aad42; Expires=tomorrow".to_string())
+                .unwrap();
+        assert_eq!(
+            eval_cookie_attribute_name(CookieAttributeName::Expires("_".to_string()), cookie),
+            None
+        );
+    }
+
     #[test]
     fn test_body() {
         let variables = HashMap::new();
@@ -838,6 +1139,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                0,
             )
             .unwrap()
             .unwrap(),
@@ -850,6 +1152,7 @@ pub mod tests {
             },
             &variables,
             &http::bytes_http_response(),
+            0,
         )
         .err()
         .unwrap();
@@ -869,7 +1172,7 @@ pub mod tests {
             body: vec![200],
             ..Default::default()
         };
-        let error = eval_query(&xpath_users(), &variables, &http_response)
+        let error = eval_query(&xpath_users(), &variables, &http_response, 0)
             .err()
             .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
@@ -902,7 +1205,7 @@ pub mod tests {
                 },
             },
         };
-        let error = eval_query(&query, &variables, &http::xml_two_users_http_response())
+        let error = eval_query(&query, &variables, &http::xml_two_users_http_response(), 0)
             .err()
             .unwrap();
         assert_eq!(error.inner, RunnerError::QueryInvalidXpathEval);
@@ -918,6 +1221,7 @@ pub mod tests {
                 &xpath_users(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                0,
             )
             .unwrap()
             .unwrap(),
@@ -928,6 +1232,7 @@ pub mod tests {
                 &xpath_count_user_query(),
                 &variables,
                 &http::xml_two_users_http_response(),
+                0,
             )
             .unwrap()
             .unwrap(),
@@ -966,6 +1271,7 @@ pub mod tests {
                 &xpath_html_charset(),
                 &variables,
                 &http::html_http_response(),
+                0,
             )
             .unwrap()
             .unwrap(),
@@ -996,7 +1302,7 @@ pub mod tests {
             },
         };
 
-        let error = eval_query(&jsonpath_query, &variables, &http::json_http_response())
+        let error = eval_query(&jsonpath_query, &variables, &http::json_http_response(), 0)
             .err()
             .unwrap();
         assert_eq!(
@@ -1021,7 +1327,7 @@ pub mod tests {
             body: String::into_bytes(String::from("xxx")),
             ..Default::default()
         };
-        let error = eval_query(&jsonpath_success(), &variables, &http_response)
+        let error = eval_query(&jsonpath_success(), &variables, &http_response, 0)
             .err()
             .unwrap();
         assert_eq!(error.source_info.start, Pos { line: 1, column: 1 });
@@ -1037,7 +1343,7 @@ pub mod tests {
         };
         //assert_eq!(jsonpath_success().eval(http_response).unwrap(), Value::List(vec![]));
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http_response).unwrap(),
+            eval_query(&jsonpath_success(), &variables, &http_response, 0).unwrap(),
             None
         );
     }
@@ -1046,15 +1352,25 @@ pub mod tests {
     fn test_query_json() {
         let variables = HashMap::new();
         assert_eq!(
-            eval_query(&jsonpath_success(), &variables, &http::json_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &jsonpath_success(),
+                &variables,
+                &http::json_http_response(),
+                0
+            )
+            .unwrap()
+            .unwrap(),
             Value::Bool(false)
         );
         assert_eq!(
-            eval_query(&jsonpath_errors(), &variables, &http::json_http_response())
-                .unwrap()
-                .unwrap(),
+            eval_query(
+                &jsonpath_errors(),
+                &variables,
+                &http::json_http_response(),
+                0
+            )
+            .unwrap()
+            .unwrap(),
             Value::List(vec![
                 Value::Object(vec![(
                     String::from("id"),
@@ -1072,15 +1388,20 @@ pub mod tests {
     fn test_query_regex() {
         let variables = HashMap::new();
         assert_eq!(
-            eval_query(&regex_name(), &variables, &http::hello_http_response())
+            eval_query(&regex_name(), &variables, &http::hello_http_response(), 0)
                 .unwrap()
                 .unwrap(),
             Value::String("World".to_string())
         );
 
-        let error = eval_query(&regex_invalid(), &variables, &http::hello_http_response())
-            .err()
-            .unwrap();
+        let error = eval_query(
+            &regex_invalid(),
+            &variables,
+            &http::hello_http_response(),
+            0,
+        )
+        .err()
+        .unwrap();
         assert_eq!(error.source_info, SourceInfo::new(1, 7, 1, 10));
         assert_eq!(error.inner, RunnerError::InvalidRegex);
     }
@@ -1096,6 +1417,7 @@ pub mod tests {
                 },
                 &variables,
                 &http::hello_http_response(),
+                0,
             )
             .unwrap()
             .unwrap(),
@@ -1116,7 +1438,8 @@ pub mod tests {
                 &http::Response {
                     body: vec![0xff],
                     ..Default::default()
-                }
+                },
+                0
             )
             .unwrap()
             .unwrap(),
@@ -1126,6 +1449,173 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_query_compression_ratio() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(0, 0, 0, 0),
+            value: QueryValue::CompressionRatio,
+        };
+        let mut encoder = libflate::gzip::Encoder::new(Vec::new()).unwrap();
+        encoder.write_all(&[b'a'; 1000]).unwrap();
+        let body = encoder.finish().into_result().unwrap();
+        let compressed_length = body.len() as f64;
+        let response = http::Response {
+            headers: vec![http::Header::new("Content-Encoding", "gzip")],
+            body,
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Float(1.0 - compressed_length / 1000.0)
+        );
+
+        // An uncompressed response has no compression gain.
+        let response = http::Response {
+            body: b"Hello World!".to_vec(),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Float(0.0)
+        );
+    }
+
+    #[test]
+    fn test_query_freshness() {
+        let variables = HashMap::new();
+        let query = |value| Query {
+            source_info: SourceInfo::new(0, 0, 0, 0),
+            value,
+        };
+        let response = |age: &str| http::Response {
+            headers: vec![
+                http::Header::new("Cache-Control", "public, max-age=3600"),
+                http::Header::new("Age", age),
+            ],
+            ..Default::default()
+        };
+        let fresh = response("600");
+        let stale = response("4000");
+
+        assert_eq!(
+            eval_query(&query(QueryValue::FreshnessLifetime), &variables, &fresh, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(3600)
+        );
+        assert_eq!(
+            eval_query(&query(QueryValue::IsFresh), &variables, &fresh, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval_query(&query(QueryValue::FreshnessLifetime), &variables, &stale, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(3600)
+        );
+        assert_eq!(
+            eval_query(&query(QueryValue::IsFresh), &variables, &stale, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(false)
+        );
+
+        // Without s-maxage, max-age or Expires, there is no freshness lifetime.
+        let response = http::Response::default();
+        assert_eq!(
+            eval_query(
+                &query(QueryValue::FreshnessLifetime),
+                &variables,
+                &response,
+                0
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            eval_query(&query(QueryValue::IsFresh), &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(false)
+        );
+    }
+
+    #[test]
+    fn test_query_tls_resumed() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(0, 0, 0, 0),
+            value: QueryValue::TlsResumed,
+        };
+        let response = http::Response {
+            tls_resumed: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(true)
+        );
+        let response = http::Response {
+            tls_resumed: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(false)
+        );
+
+        // The TLS backend doesn't let us know if the TLS session is resumed.
+        assert_eq!(
+            eval_query(&query, &variables, &http::Response::default(), 0).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_request() {
+        let response = http::Response {
+            request_line: "GET /hello HTTP/1.1".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_request(&response, RequestAttributeName::Version).unwrap(),
+            Value::String("HTTP/1.1".to_string())
+        );
+        assert_eq!(
+            eval_query_request(&response, RequestAttributeName::Line).unwrap(),
+            Value::String("GET /hello HTTP/1.1".to_string())
+        );
+
+        let response = http::Response {
+            request_line: "POST /api?page=1 HTTP/2".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_request(&response, RequestAttributeName::Version).unwrap(),
+            Value::String("HTTP/2".to_string())
+        );
+        assert_eq!(
+            eval_query_request(&response, RequestAttributeName::Line).unwrap(),
+            Value::String("POST /api?page=1 HTTP/2".to_string())
+        );
+
+        assert_eq!(
+            eval_query_request(&http::Response::default(), RequestAttributeName::Version),
+            None
+        );
+    }
+
     #[test]
     fn test_query_certificate() {
         assert!(eval_query_certificate(
@@ -1144,7 +1634,10 @@ pub mod tests {
                         issuer: String::new(),
                         start_date: Default::default(),
                         expire_date: Default::default(),
-                        serial_number: String::new()
+                        serial_number: String::new(),
+                        chain_length: 1,
+                        ocsp_verified: false,
+                        pem: None,
                     }),
                     ..Default::default()
                 },
@@ -1155,4 +1648,345 @@ pub mod tests {
             Value::String("A=B, C=D".to_string())
         );
     }
+
+    #[test]
+    fn test_query_certificate_chain() {
+        let self_signed = http::Response {
+            certificate: Some(http::Certificate {
+                subject: "CN = localhost".to_string(),
+                issuer: "CN = localhost".to_string(),
+                start_date: Default::default(),
+                expire_date: Default::default(),
+                serial_number: String::new(),
+                chain_length: 1,
+                ocsp_verified: false,
+                pem: None,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_certificate(&self_signed, CertificateAttributeName::SelfSigned)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(true)
+        );
+
+        let signed_by_ca = http::Response {
+            certificate: Some(http::Certificate {
+                subject: "CN = localhost".to_string(),
+                issuer: "CN = Acme Root CA".to_string(),
+                start_date: Default::default(),
+                expire_date: Default::default(),
+                serial_number: String::new(),
+                chain_length: 2,
+                ocsp_verified: false,
+                pem: None,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_certificate(&signed_by_ca, CertificateAttributeName::SelfSigned)
+                .unwrap()
+                .unwrap(),
+            Value::Bool(false)
+        );
+        assert_eq!(
+            eval_query_certificate(&signed_by_ca, CertificateAttributeName::ChainLength)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(2)
+        );
+    }
+
+    #[test]
+    fn test_query_certificate_ocsp_status() {
+        let mut certificate = http::Certificate {
+            subject: "CN = localhost".to_string(),
+            issuer: "CN = Acme Root CA".to_string(),
+            start_date: Default::default(),
+            expire_date: Default::default(),
+            serial_number: String::new(),
+            chain_length: 2,
+            ocsp_verified: false,
+            pem: None,
+        };
+        // Without status verification, the OCSP status is unknown.
+        let response = http::Response {
+            certificate: Some(certificate.clone()),
+            ..Default::default()
+        };
+        assert!(
+            eval_query_certificate(&response, CertificateAttributeName::OcspStatus)
+                .unwrap()
+                .is_none()
+        );
+
+        certificate.ocsp_verified = true;
+        let response = http::Response {
+            certificate: Some(certificate),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_certificate(&response, CertificateAttributeName::OcspStatus)
+                .unwrap()
+                .unwrap(),
+            Value::String("good".to_string())
+        );
+    }
+
+    #[test]
+    fn test_query_reason_phrase() {
+        let response = http::Response {
+            status: 404,
+            reason: "NOT FOUND".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_reason_phrase(&response).unwrap().unwrap(),
+            Value::String("NOT FOUND".to_string())
+        );
+        assert_eq!(
+            eval_query_reason_phrase(&http::Response::default())
+                .unwrap()
+                .unwrap(),
+            Value::String(String::new())
+        );
+    }
+
+    #[test]
+    fn test_query_pseudo_header() {
+        let variables = HashMap::new();
+        let name = |value: &str| Template {
+            delimiter: Some('"'),
+            elements: vec![TemplateElement::String {
+                value: value.to_string(),
+                encoded: value.to_string(),
+            }],
+            source_info: SourceInfo::new(0, 0, 0, 0),
+        };
+        let response = http::Response {
+            version: http::Version::Http2,
+            status: 204,
+            headers: vec![http::Header::new("content-length", "0")],
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_pseudo_header(&response, &name(":status"), &variables)
+                .unwrap()
+                .unwrap(),
+            Value::String("204".to_string())
+        );
+        assert_eq!(
+            eval_query_pseudo_header(&response, &name(":path"), &variables).unwrap(),
+            None
+        );
+        // Pseudo-headers are distinct from the response headers
+        assert_eq!(
+            eval_query_header(&response, &name(":status"), &variables).unwrap(),
+            None
+        );
+
+        // No pseudo-header in HTTP/1.1
+        let response = http::Response {
+            version: http::Version::Http11,
+            status: 204,
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_pseudo_header(&response, &name(":status"), &variables).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_content_length() {
+        let variables = HashMap::new();
+        let query = |value| Query {
+            source_info: SourceInfo::new(1, 1, 1, 1),
+            value,
+        };
+        let response = http::Response {
+            headers: vec![http::Header::new("Content-Length", "5")],
+            body: b"Hello".to_vec(),
+            transfer: http::Transfer {
+                size_excess: 7,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query(QueryValue::ContentLength), &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(5)
+        );
+        assert_eq!(
+            eval_query(&query(QueryValue::BodyLength), &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(12)
+        );
+        assert_eq!(
+            eval_query(
+                &query(QueryValue::ContentLength),
+                &variables,
+                &http::Response::default(),
+                0
+            )
+            .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_content_type() {
+        let variables = HashMap::new();
+        let query = Query {
+            source_info: SourceInfo::new(1, 1, 1, 1),
+            value: QueryValue::ContentType,
+        };
+        let response = http::Response {
+            headers: vec![http::Header::new(
+                "Content-Type",
+                "application/json; charset=utf-8",
+            )],
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query(&query, &variables, &response, 0)
+                .unwrap()
+                .unwrap(),
+            Value::String("application/json".to_string())
+        );
+        assert_eq!(
+            eval_query(&query, &variables, &http::Response::default(), 0).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_query_transfer() {
+        let response = http::Response {
+            transfer: http::Transfer {
+                size_download: 12,
+                speed_download: 2400,
+                speed_upload: 0,
+                size_excess: 0,
+            },
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_transfer(&response, TransferAttributeName::SizeDownload)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(12)
+        );
+        assert_eq!(
+            eval_query_transfer(&response, TransferAttributeName::SpeedDownload)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(2400)
+        );
+        assert_eq!(
+            eval_query_transfer(&response, TransferAttributeName::SpeedUpload)
+                .unwrap()
+                .unwrap(),
+            Value::Integer(0)
+        );
+    }
+
+    #[test]
+    fn test_query_redirects() {
+        let response = http::Response {
+            status: 200,
+            redirects: vec![
+                http::Redirect {
+                    url: "http://localhost:8000/redirect-301".to_string(),
+                    status: 301,
+                    headers: vec![http::Header::new("Location", "/redirect-302")],
+                },
+                http::Redirect {
+                    url: "http://localhost:8000/redirect-302".to_string(),
+                    status: 302,
+                    headers: vec![],
+                },
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            eval_query_redirects(&response).unwrap().unwrap(),
+            Value::Integer(2)
+        );
+        assert_eq!(
+            eval_query_redirects(&http::hello_http_response())
+                .unwrap()
+                .unwrap(),
+            Value::Integer(0)
+        );
+
+        let variables = HashMap::new();
+        let source_info = SourceInfo::new(1, 1, 1, 1);
+        assert_eq!(
+            eval_query_redirect(
+                &response,
+                0,
+                &RedirectAttribute::Status,
+                &variables,
+                &source_info
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Integer(301)
+        );
+        assert_eq!(
+            eval_query_redirect(
+                &response,
+                1,
+                &RedirectAttribute::Status,
+                &variables,
+                &source_info
+            )
+            .unwrap()
+            .unwrap(),
+            Value::Integer(302)
+        );
+        let location = RedirectAttribute::Header {
+            space0: Whitespace {
+                value: String::from(" "),
+                source_info: SourceInfo::new(0, 0, 0, 0),
+            },
+            name: Template {
+                delimiter: Some('"'),
+                elements: vec![TemplateElement::String {
+                    value: "location".to_string(),
+                    encoded: "location".to_string(),
+                }],
+                source_info: SourceInfo::new(0, 0, 0, 0),
+            },
+        };
+        assert_eq!(
+            eval_query_redirect(&response, 0, &location, &variables, &source_info)
+                .unwrap()
+                .unwrap(),
+            Value::String("/redirect-302".to_string())
+        );
+        assert!(
+            eval_query_redirect(&response, 1, &location, &variables, &source_info)
+                .unwrap()
+                .is_none()
+        );
+        let error = eval_query_redirect(
+            &response,
+            2,
+            &RedirectAttribute::Status,
+            &variables,
+            &source_info,
+        )
+        .err()
+        .unwrap();
+        assert_eq!(
+            error.inner,
+            RunnerError::QueryRedirectNotFound { index: 2, count: 2 }
+        );
+    }
 }
@@ -65,6 +65,7 @@ pub fn eval_asserts(
     variables: &HashMap<String, Value>,
     http_response: &http::Response,
     context_dir: &ContextDir,
+    retries: usize,
 ) -> Vec<AssertResult> {
     let mut asserts = vec![];
 
@@ -131,7 +132,7 @@ pub fn eval_asserts(
     }
 
     for assert in response.asserts().iter() {
-        let assert_result = eval_assert(assert, variables, http_response);
+        let assert_result = eval_assert(assert, variables, http_response, retries);
         asserts.push(assert_result);
     }
     asserts
@@ -314,10 +315,11 @@ pub fn eval_captures(
     response: &Response,
     http_response: &http::Response,
     variables: &mut HashMap<String, Value>,
+    retries: usize,
 ) -> Result<Vec<CaptureResult>, Error> {
     let mut captures = vec![];
     for capture in response.captures().iter() {
-        let capture_result = eval_capture(capture, variables, http_response)?;
+        let capture_result = eval_capture(capture, variables, http_response, retries)?;
         // Update variables now so the captures set is ready in case
         // the next captures reference this new variable.
         variables.insert(capture_result.name.clone(), capture_result.value.clone());
@@ -387,6 +389,7 @@ mod tests {
                 &variables,
                 &http::xml_two_users_http_response(),
                 &context_dir,
+                0,
             ),
             vec![AssertResult::Explicit {
                 actual: Ok(Some(Value::Integer(2))),
@@ -431,6 +434,7 @@ mod tests {
                 &user_response(),
                 &http::xml_two_users_http_response(),
                 &mut variables,
+                0,
             )
             .unwrap(),
             vec![CaptureResult {
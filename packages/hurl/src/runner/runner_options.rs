@@ -33,6 +33,7 @@ pub struct RunnerOptionsBuilder {
     context_dir: ContextDir,
     continue_on_error: bool,
     cookie_input_file: Option<String>,
+    data_urlencode: Option<String>,
     follow_location: bool,
     ignore_asserts: bool,
     insecure: bool,
@@ -42,14 +43,19 @@ pub struct RunnerOptionsBuilder {
     post_entry: Option<fn() -> bool>,
     pre_entry: Option<fn(Entry) -> bool>,
     proxy: Option<String>,
+    proxy_cacert_file: Option<String>,
+    reload_ca_store: bool,
     resolves: Vec<String>,
     retry: Retry,
     retry_interval: Duration,
+    slow_threshold: Option<Duration>,
     ssl_no_revoke: bool,
     timeout: Duration,
     to_entry: Option<usize>,
     user: Option<String>,
     user_agent: Option<String>,
+    verify_status: bool,
+    websocket: bool,
 }
 
 impl Default for RunnerOptionsBuilder {
@@ -66,6 +72,7 @@ impl Default for RunnerOptionsBuilder {
             context_dir: ContextDir::default(),
             continue_on_error: false,
             cookie_input_file: None,
+            data_urlencode: None,
             follow_location: false,
             ignore_asserts: false,
             insecure: false,
@@ -75,14 +82,19 @@ impl Default for RunnerOptionsBuilder {
             post_entry: None,
             pre_entry: None,
             proxy: None,
+            proxy_cacert_file: None,
+            reload_ca_store: false,
             resolves: vec![],
             retry: Retry::None,
             retry_interval: Duration::from_millis(1000),
+            slow_threshold: None,
             ssl_no_revoke: false,
             timeout: Duration::from_secs(300),
             to_entry: None,
             user: None,
             user_agent: None,
+            verify_status: false,
+            websocket: false,
         }
     }
 }
@@ -173,6 +185,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets an urlencoded body, sent with the `application/x-www-form-urlencoded` content type
+    /// by the request of a single entry Hurl file, if it has no body. The method of the request is
+    /// not changed.
+    pub fn data_urlencode(&mut self, data_urlencode: Option<String>) -> &mut Self {
+        self.data_urlencode = data_urlencode;
+        self
+    }
+
     /// Sets stopping or continuing executing requests to the end of the Hurl file even when an assert error occurs.
     ///
     /// By default, Hurl exits after an assert error in the HTTP response. Note that this option does
@@ -244,6 +264,23 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the CA certificate file used to verify the HTTPS proxy.
+    ///
+    /// The origin server is still verified against the default CA certificates, or the one
+    /// given with [`RunnerOptionsBuilder::cacert_file`].
+    pub fn proxy_cacert_file(&mut self, proxy_cacert_file: Option<String>) -> &mut Self {
+        self.proxy_cacert_file = proxy_cacert_file;
+        self
+    }
+
+    /// Re-reads the CA certificates store for each connection, instead of caching it.
+    ///
+    /// Default is false.
+    pub fn reload_ca_store(&mut self, reload_ca_store: bool) -> &mut Self {
+        self.reload_ca_store = reload_ca_store;
+        self
+    }
+
     /// Provides a custom address for a specific host and port pair.
     pub fn resolves(&mut self, resolves: &[String]) -> &mut Self {
         self.resolves = resolves.to_vec();
@@ -266,6 +303,14 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Sets the duration above which an entry is reported as slow.
+    ///
+    /// A slow entry is only a warning, it doesn't fail the run.
+    pub fn slow_threshold(&mut self, slow_threshold: Option<Duration>) -> &mut Self {
+        self.slow_threshold = slow_threshold;
+        self
+    }
+
     pub fn ssl_no_revoke(&mut self, ssl_no_revoke: bool) -> &mut Self {
         self.ssl_no_revoke = ssl_no_revoke;
         self
@@ -297,6 +342,22 @@ impl RunnerOptionsBuilder {
         self
     }
 
+    /// Verifies the status of the server certificate with OCSP stapling: a missing or invalid
+    /// stapled OCSP response fails the transfer.
+    pub fn verify_status(&mut self, verify_status: bool) -> &mut Self {
+        self.verify_status = verify_status;
+        self
+    }
+
+    /// Performs a WebSocket opening handshake.
+    ///
+    /// The `Upgrade`, `Connection`, `Sec-WebSocket-Key` and `Sec-WebSocket-Version` headers are
+    /// added to the request, and the `Sec-WebSocket-Accept` header of a `101` response is checked.
+    pub fn websocket(&mut self, websocket: bool) -> &mut Self {
+        self.websocket = websocket;
+        self
+    }
+
     /// Create an instance of [`RunnerOptions`].
     pub fn build(&self) -> RunnerOptions {
         RunnerOptions {
@@ -311,6 +372,7 @@ impl RunnerOptionsBuilder {
             context_dir: self.context_dir.clone(),
             continue_on_error: self.continue_on_error,
             cookie_input_file: self.cookie_input_file.clone(),
+            data_urlencode: self.data_urlencode.clone(),
             follow_location: self.follow_location,
             ignore_asserts: self.ignore_asserts,
             insecure: self.insecure,
@@ -320,14 +382,19 @@ impl RunnerOptionsBuilder {
             post_entry: self.post_entry,
             pre_entry: self.pre_entry,
             proxy: self.proxy.clone(),
+            proxy_cacert_file: self.proxy_cacert_file.clone(),
+            reload_ca_store: self.reload_ca_store,
             resolves: self.resolves.clone(),
             retry: self.retry,
             retry_interval: self.retry_interval,
+            slow_threshold: self.slow_threshold,
             ssl_no_revoke: self.ssl_no_revoke,
             timeout: self.timeout,
             to_entry: self.to_entry,
             user: self.user.clone(),
             user_agent: self.user_agent.clone(),
+            verify_status: self.verify_status,
+            websocket: self.websocket,
         }
     }
 }
@@ -345,6 +412,7 @@ pub struct RunnerOptions {
     pub(crate) context_dir: ContextDir,
     pub(crate) continue_on_error: bool,
     pub(crate) cookie_input_file: Option<String>,
+    pub(crate) data_urlencode: Option<String>,
     pub(crate) follow_location: bool,
     pub(crate) ignore_asserts: bool,
     pub(crate) insecure: bool,
@@ -354,14 +422,19 @@ pub struct RunnerOptions {
     pub(crate) post_entry: Option<fn() -> bool>,
     pub(crate) pre_entry: Option<fn(Entry) -> bool>,
     pub(crate) proxy: Option<String>,
+    pub(crate) proxy_cacert_file: Option<String>,
+    pub(crate) reload_ca_store: bool,
     pub(crate) resolves: Vec<String>,
     pub(crate) retry: Retry,
     pub(crate) retry_interval: Duration,
+    pub(crate) slow_threshold: Option<Duration>,
     pub(crate) ssl_no_revoke: bool,
     pub(crate) timeout: Duration,
     pub(crate) to_entry: Option<usize>,
     pub(crate) user: Option<String>,
     pub(crate) user_agent: Option<String>,
+    pub(crate) verify_status: bool,
+    pub(crate) websocket: bool,
 }
 
 impl Default for RunnerOptions {
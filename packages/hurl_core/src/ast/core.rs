@@ -298,16 +298,34 @@ pub struct Query {
 #[allow(clippy::large_enum_variant)]
 pub enum QueryValue {
     Status,
+    ReasonPhrase,
     Url,
     Header {
         space0: Whitespace,
         name: Template,
     },
+    PseudoHeader {
+        space0: Whitespace,
+        name: Template,
+    },
     Cookie {
         space0: Whitespace,
         expr: CookiePath,
     },
+    CookieNth {
+        space0: Whitespace,
+        space1: Whitespace,
+        index: u64,
+        space2: Whitespace,
+        attribute: CookieNthAttribute,
+    },
     Body,
+    BodyLength,
+    CompressionRatio,
+    ContentLength,
+    ContentType,
+    FreshnessLifetime,
+    IsFresh,
     Xpath {
         space0: Whitespace,
         expr: Template,
@@ -325,6 +343,7 @@ pub enum QueryValue {
         name: Template,
     },
     Duration,
+    Retries,
     Bytes,
     Sha256,
     Md5,
@@ -332,6 +351,22 @@ pub enum QueryValue {
         space0: Whitespace,
         attribute_name: CertificateAttributeName,
     },
+    TlsResumed,
+    Transfer {
+        space0: Whitespace,
+        attribute_name: TransferAttributeName,
+    },
+    Redirects,
+    Redirect {
+        space0: Whitespace,
+        index: u64,
+        space1: Whitespace,
+        attribute: RedirectAttribute,
+    },
+    Request {
+        space0: Whitespace,
+        attribute_name: RequestAttributeName,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -387,6 +422,35 @@ pub enum CertificateAttributeName {
     StartDate,
     ExpireDate,
     SerialNumber,
+    ChainLength,
+    SelfSigned,
+    OcspStatus,
+    Pem,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransferAttributeName {
+    SizeDownload,
+    SpeedDownload,
+    SpeedUpload,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RequestAttributeName {
+    Version,
+    Line,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CookieNthAttribute {
+    Name,
+    Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RedirectAttribute {
+    Status,
+    Header { space0: Whitespace, name: Template },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -476,6 +540,12 @@ pub enum PredicateFuncValue {
         space0: Whitespace,
         value: PredicateValue,
     },
+    OneOf {
+        values: Vec<(Whitespace, PredicateValue)>,
+    },
+    HasExactlyKeys {
+        keys: Vec<(Whitespace, Template)>,
+    },
     IsInteger,
     IsFloat,
     IsBoolean,
@@ -484,6 +554,8 @@ pub enum PredicateFuncValue {
     IsDate,
     Exist,
     IsEmpty,
+    IsSorted,
+    IsSortedDescending,
 }
 
 //
@@ -719,20 +791,24 @@ pub enum OptionKind {
     CaCertificate(Filename),
     ClientCert(Filename),
     ClientKey(Filename),
+    ConnectTimeout(Duration),
     ConnectTo(String),
-    Delay(u64),
+    Delay(Duration),
     Compressed(bool),
     Insecure(bool),
     FollowLocation(bool),
     MaxRedirect(usize),
+    MaxTime(Duration),
     PathAsIs(bool),
     Proxy(String),
     Resolve(String),
     Retry(Retry),
-    RetryInterval(u64),
+    RetryInterval(Duration),
     Variable(VariableDefinition),
     Verbose(bool),
+    VerifyStatus(bool),
     VeryVerbose(bool),
+    WebSocket(bool),
 }
 
 impl OptionKind {
@@ -743,11 +819,13 @@ impl OptionKind {
             OptionKind::ClientCert(_) => "cert",
             OptionKind::ClientKey(_) => "key",
             OptionKind::Compressed(_) => "compressed",
+            OptionKind::ConnectTimeout(_) => "connect-timeout",
             OptionKind::ConnectTo(_) => "connect-to",
             OptionKind::Delay(_) => "delay",
             OptionKind::Insecure(_) => "insecure",
             OptionKind::FollowLocation(_) => "location",
             OptionKind::MaxRedirect(_) => "max-redirs",
+            OptionKind::MaxTime(_) => "max-time",
             OptionKind::PathAsIs(_) => "path-as-is",
             OptionKind::Proxy(_) => "proxy",
             OptionKind::Resolve(_) => "resolve",
@@ -755,7 +833,9 @@ impl OptionKind {
             OptionKind::RetryInterval(_) => "retry-interval",
             OptionKind::Variable(_) => "variable",
             OptionKind::Verbose(_) => "verbose",
+            OptionKind::VerifyStatus(_) => "verify-status",
             OptionKind::VeryVerbose(_) => "very-verbose",
+            OptionKind::WebSocket(_) => "websocket",
         }
     }
 
@@ -766,11 +846,13 @@ impl OptionKind {
             OptionKind::ClientCert(filename) => filename.value.clone(),
             OptionKind::ClientKey(filename) => filename.value.clone(),
             OptionKind::Compressed(value) => value.to_string(),
+            OptionKind::ConnectTimeout(value) => value.to_string(),
             OptionKind::ConnectTo(value) => value.clone(),
             OptionKind::Delay(value) => value.to_string(),
             OptionKind::Insecure(value) => value.to_string(),
             OptionKind::FollowLocation(value) => value.to_string(),
             OptionKind::MaxRedirect(value) => value.to_string(),
+            OptionKind::MaxTime(value) => value.to_string(),
             OptionKind::PathAsIs(value) => value.to_string(),
             OptionKind::Proxy(value) => value.clone(),
             OptionKind::Resolve(value) => value.clone(),
@@ -780,7 +862,9 @@ impl OptionKind {
                 format!("{name}={value}")
             }
             OptionKind::Verbose(value) => value.to_string(),
+            OptionKind::VerifyStatus(value) => value.to_string(),
             OptionKind::VeryVerbose(value) => value.to_string(),
+            OptionKind::WebSocket(value) => value.to_string(),
         }
     }
 }
@@ -810,6 +894,13 @@ pub struct Filter {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum FilterValue {
+    Abs,
+    Base64Encode,
+    Base64UrlDecode,
+    Ceil,
+    Coalesce {
+        values: Vec<(Whitespace, Template)>,
+    },
     Count,
     DaysAfterNow,
     DaysBeforeNow,
@@ -817,16 +908,62 @@ pub enum FilterValue {
         space0: Whitespace,
         encoding: Template,
     },
+    Drop {
+        space0: Whitespace,
+        n: u64,
+    },
+    FirstMatch {
+        space0: Whitespace,
+        predicate: Predicate,
+    },
+    Floor,
+    FormDecode,
     Format {
         space0: Whitespace,
         fmt: Template,
     },
+    HmacSha256 {
+        space0: Whitespace,
+        key: PredicateValue,
+    },
     HtmlEscape,
     HtmlUnescape,
+    IndexOf {
+        space0: Whitespace,
+        needle: PredicateValue,
+    },
+    Join {
+        space0: Whitespace,
+        sep: Template,
+    },
+    JsonPath {
+        space0: Whitespace,
+        expr: Template,
+    },
+    LastMatch {
+        space0: Whitespace,
+        predicate: Predicate,
+    },
+    Mask {
+        space0: Whitespace,
+        keep_start: u64,
+        space1: Whitespace,
+        keep_end: u64,
+    },
+    Normalize {
+        space0: Whitespace,
+        form: Template,
+    },
     Nth {
         space0: Whitespace,
         n: u64,
     },
+    ParseUrl,
+    PemToDer,
+    QueryParam {
+        space0: Whitespace,
+        name: Template,
+    },
     Regex {
         space0: Whitespace,
         value: RegexValue,
@@ -837,21 +974,39 @@ pub enum FilterValue {
         space1: Whitespace,
         new_value: Template,
     },
+    Round {
+        decimals: Option<(Whitespace, u64)>,
+    },
+    Sha256,
     Split {
         space0: Whitespace,
         sep: Template,
     },
+    Take {
+        space0: Whitespace,
+        n: u64,
+    },
     ToDate {
         space0: Whitespace,
         fmt: Template,
     },
+    ToHex,
     ToInt,
+    Truncate {
+        space0: Whitespace,
+        n: u64,
+        ellipsis: Option<(Whitespace, Template)>,
+    },
     UrlDecode,
     UrlEncode,
     XPath {
         space0: Whitespace,
         expr: Template,
     },
+    Zip {
+        space0: Whitespace,
+        expr: Expr,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
@@ -860,3 +1015,35 @@ pub enum Retry {
     Finite(usize),
     Infinite,
 }
+
+/// A duration, like `500ms` or `2s`. Without unit, the unit depends on the option using the
+/// duration.
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub struct Duration {
+    pub value: u64,
+    pub unit: Option<DurationUnit>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Copy)]
+pub enum DurationUnit {
+    MilliSecond,
+    Second,
+    Minute,
+}
+
+impl Duration {
+    pub fn new(value: u64, unit: Option<DurationUnit>) -> Duration {
+        Duration { value, unit }
+    }
+
+    /// Converts this duration to a [`std::time::Duration`], using `default_unit` if this duration
+    /// has no unit. Durations in minutes are checked against overflow when parsed, the conversion
+    /// saturates otherwise.
+    pub fn to_std(&self, default_unit: DurationUnit) -> std::time::Duration {
+        match self.unit.unwrap_or(default_unit) {
+            DurationUnit::MilliSecond => std::time::Duration::from_millis(self.value),
+            DurationUnit::Second => std::time::Duration::from_secs(self.value),
+            DurationUnit::Minute => std::time::Duration::from_secs(self.value.saturating_mul(60)),
+        }
+    }
+}
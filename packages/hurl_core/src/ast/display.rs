@@ -227,6 +227,8 @@ impl PredicateFuncValue {
             PredicateFuncValue::Contain { .. } => "contains".to_string(),
             PredicateFuncValue::Include { .. } => "includes".to_string(),
             PredicateFuncValue::Match { .. } => "matches".to_string(),
+            PredicateFuncValue::OneOf { .. } => "oneOf".to_string(),
+            PredicateFuncValue::HasExactlyKeys { .. } => "hasExactlyKeys".to_string(),
             PredicateFuncValue::IsInteger => "isInteger".to_string(),
             PredicateFuncValue::IsFloat => "isFloat".to_string(),
             PredicateFuncValue::IsBoolean => "isBoolean".to_string(),
@@ -235,6 +237,8 @@ impl PredicateFuncValue {
             PredicateFuncValue::IsDate => "isDate".to_string(),
             PredicateFuncValue::Exist => "exists".to_string(),
             PredicateFuncValue::IsEmpty => "isEmpty".to_string(),
+            PredicateFuncValue::IsSorted => "isSorted".to_string(),
+            PredicateFuncValue::IsSortedDescending => "isSortedDescending".to_string(),
         }
     }
 }
@@ -250,6 +254,26 @@ impl fmt::Display for Retry {
     }
 }
 
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Some(unit) => write!(f, "{}{}", self.value, unit),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+impl fmt::Display for DurationUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            DurationUnit::MilliSecond => "ms",
+            DurationUnit::Second => "s",
+            DurationUnit::Minute => "m",
+        };
+        write!(f, "{}", value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
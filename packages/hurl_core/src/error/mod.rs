@@ -62,6 +62,9 @@ impl Error for parser::Error {
             ParseError::UrlInvalidStart => "Parsing URL".to_string(),
             ParseError::Multiline => "Parsing multiline".to_string(),
             ParseError::GraphQlVariables => "Parsing GraphQL variables".to_string(),
+            ParseError::InvalidDuration => "Parsing duration".to_string(),
+            ParseError::InvalidDurationUnit(_) => "Parsing duration".to_string(),
+            ParseError::DurationOverflow => "Parsing duration".to_string(),
             _ => format!("{self:?}"),
         }
     }
@@ -116,6 +119,14 @@ impl Error for parser::Error {
             ParseError::UrlInvalidStart => "expecting http://, https:// or {{".to_string(),
             ParseError::Multiline => "the multiline is not valid".to_string(),
             ParseError::GraphQlVariables => "GraphQL variables is not a valid JSON object".to_string(),
+            ParseError::InvalidDuration => "expecting a positive integer with an optional unit (ms, s or m)".to_string(),
+            ParseError::InvalidDurationUnit(unit)
+            => format!("the duration unit <{unit}> is not valid. {}", did_you_mean(
+                &["ms", "s", "m"],
+                unit.as_str(),
+                "Valid values are ms, s or m",
+            )),
+            ParseError::DurationOverflow => "the duration is too large".to_string(),
             _ => format!("{self:?}"),
 
         }
@@ -218,24 +218,35 @@ impl HtmlFormatter {
             OptionKind::ClientCert(filename) => self.fmt_filename(filename),
             OptionKind::ClientKey(filename) => self.fmt_filename(filename),
             OptionKind::Compressed(value) => self.fmt_bool(*value),
+            OptionKind::ConnectTimeout(value) => self.fmt_duration(value),
             OptionKind::ConnectTo(value) => self.fmt_string(value),
-            OptionKind::Delay(value) => self.fmt_number(value),
+            OptionKind::Delay(value) => self.fmt_duration(value),
             OptionKind::Insecure(value) => self.fmt_bool(*value),
             OptionKind::FollowLocation(value) => self.fmt_bool(*value),
             OptionKind::MaxRedirect(value) => self.fmt_number(value),
+            OptionKind::MaxTime(value) => self.fmt_duration(value),
             OptionKind::PathAsIs(value) => self.fmt_bool(*value),
             OptionKind::Proxy(value) => self.fmt_string(value),
             OptionKind::Resolve(value) => self.fmt_string(value),
             OptionKind::Retry(value) => self.fmt_retry(value),
-            OptionKind::RetryInterval(value) => self.fmt_number(value),
+            OptionKind::RetryInterval(value) => self.fmt_duration(value),
             OptionKind::Variable(value) => self.fmt_variable_definition(value),
             OptionKind::Verbose(value) => self.fmt_bool(*value),
+            OptionKind::VerifyStatus(value) => self.fmt_bool(*value),
             OptionKind::VeryVerbose(value) => self.fmt_bool(*value),
+            OptionKind::WebSocket(value) => self.fmt_bool(*value),
         };
         self.fmt_span_close();
         self.fmt_lt(&option.line_terminator0);
     }
 
+    fn fmt_duration(&mut self, duration: &Duration) {
+        self.fmt_number(duration.value);
+        if let Some(unit) = duration.unit {
+            self.buffer.push_str(&unit.to_string());
+        }
+    }
+
     fn fmt_retry(&mut self, retry: &Retry) {
         match retry {
             Retry::Finite(n) => self.fmt_number(n),
@@ -337,18 +348,45 @@ impl HtmlFormatter {
     fn fmt_query_value(&mut self, query_value: &QueryValue) {
         match query_value {
             QueryValue::Status => self.fmt_span("query-type", "status"),
+            QueryValue::ReasonPhrase => self.fmt_span("query-type", "reasonPhrase"),
             QueryValue::Url => self.fmt_span("query-type", "url"),
             QueryValue::Header { space0, name } => {
                 self.fmt_span("query-type", "header");
                 self.fmt_space(space0);
                 self.fmt_template(name);
             }
+            QueryValue::PseudoHeader { space0, name } => {
+                self.fmt_span("query-type", "pseudoHeader");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
             QueryValue::Cookie { space0, expr } => {
                 self.fmt_span("query-type", "cookie");
                 self.fmt_space(space0);
                 self.fmt_cookie_path(expr);
             }
+            QueryValue::CookieNth {
+                space0,
+                space1,
+                index,
+                space2,
+                attribute,
+            } => {
+                self.fmt_span("query-type", "cookie");
+                self.fmt_space(space0);
+                self.fmt_span("query-type", "nth");
+                self.fmt_space(space1);
+                self.fmt_number(index);
+                self.fmt_space(space2);
+                self.fmt_cookie_nth_attribute(attribute);
+            }
             QueryValue::Body => self.fmt_span("query-type", "body"),
+            QueryValue::BodyLength => self.fmt_span("query-type", "bodyLength"),
+            QueryValue::CompressionRatio => self.fmt_span("query-type", "compressionRatio"),
+            QueryValue::ContentLength => self.fmt_span("query-type", "contentLength"),
+            QueryValue::ContentType => self.fmt_span("query-type", "contentType"),
+            QueryValue::FreshnessLifetime => self.fmt_span("query-type", "freshnessLifetime"),
+            QueryValue::IsFresh => self.fmt_span("query-type", "isFresh"),
             QueryValue::Xpath { space0, expr } => {
                 self.fmt_span("query-type", "xpath");
                 self.fmt_space(space0);
@@ -370,6 +408,7 @@ impl HtmlFormatter {
                 self.fmt_template(name);
             }
             QueryValue::Duration => self.fmt_span("query-type", "duration"),
+            QueryValue::Retries => self.fmt_span("query-type", "retries"),
             QueryValue::Bytes => self.fmt_span("query-type", "bytes"),
             QueryValue::Sha256 => self.fmt_span("query-type", "sha256"),
             QueryValue::Md5 => self.fmt_span("query-type", "md5"),
@@ -381,6 +420,36 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_certificate_attribute_name(field);
             }
+            QueryValue::TlsResumed => self.fmt_span("query-type", "tlsResumed"),
+            QueryValue::Transfer {
+                space0,
+                attribute_name: field,
+            } => {
+                self.fmt_span("query-type", "transfer");
+                self.fmt_space(space0);
+                self.fmt_transfer_attribute_name(field);
+            }
+            QueryValue::Redirects => self.fmt_span("query-type", "redirects"),
+            QueryValue::Redirect {
+                space0,
+                index,
+                space1,
+                attribute,
+            } => {
+                self.fmt_span("query-type", "redirect");
+                self.fmt_space(space0);
+                self.fmt_number(index);
+                self.fmt_space(space1);
+                self.fmt_redirect_attribute(attribute);
+            }
+            QueryValue::Request {
+                space0,
+                attribute_name: field,
+            } => {
+                self.fmt_span("query-type", "request");
+                self.fmt_space(space0);
+                self.fmt_request_attribute_name(field);
+            }
         }
     }
 
@@ -418,6 +487,10 @@ impl HtmlFormatter {
             CertificateAttributeName::StartDate => "Start-Date",
             CertificateAttributeName::ExpireDate => "Expire-Date",
             CertificateAttributeName::SerialNumber => "Serial-Number",
+            CertificateAttributeName::ChainLength => "Chain-Length",
+            CertificateAttributeName::SelfSigned => "Self-Signed",
+            CertificateAttributeName::OcspStatus => "OCSP-Status",
+            CertificateAttributeName::Pem => "Pem",
         };
         self.fmt_span_open("string");
         self.buffer.push('"');
@@ -426,6 +499,49 @@ impl HtmlFormatter {
         self.fmt_span_close();
     }
 
+    fn fmt_request_attribute_name(&mut self, name: &RequestAttributeName) {
+        let value = match name {
+            RequestAttributeName::Version => "Version",
+            RequestAttributeName::Line => "Line",
+        };
+        self.fmt_span_open("string");
+        self.buffer.push('"');
+        self.buffer.push_str(value);
+        self.buffer.push('"');
+        self.fmt_span_close();
+    }
+
+    fn fmt_transfer_attribute_name(&mut self, name: &TransferAttributeName) {
+        let value = match name {
+            TransferAttributeName::SizeDownload => "Size-Download",
+            TransferAttributeName::SpeedDownload => "Speed-Download",
+            TransferAttributeName::SpeedUpload => "Speed-Upload",
+        };
+        self.fmt_span_open("string");
+        self.buffer.push('"');
+        self.buffer.push_str(value);
+        self.buffer.push('"');
+        self.fmt_span_close();
+    }
+
+    fn fmt_cookie_nth_attribute(&mut self, attribute: &CookieNthAttribute) {
+        match attribute {
+            CookieNthAttribute::Name => self.fmt_span("query-type", "name"),
+            CookieNthAttribute::Value => self.fmt_span("query-type", "value"),
+        }
+    }
+
+    fn fmt_redirect_attribute(&mut self, attribute: &RedirectAttribute) {
+        match attribute {
+            RedirectAttribute::Status => self.fmt_span("query-type", "status"),
+            RedirectAttribute::Header { space0, name } => {
+                self.fmt_span("query-type", "header");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
+        }
+    }
+
     fn fmt_assert(&mut self, assert: &Assert) {
         self.fmt_lts(&assert.line_terminators);
         self.fmt_span_open("line");
@@ -503,6 +619,18 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_predicate_value(value);
             }
+            PredicateFuncValue::OneOf { values } => {
+                for (space, value) in values {
+                    self.fmt_space(space);
+                    self.fmt_predicate_value(value);
+                }
+            }
+            PredicateFuncValue::HasExactlyKeys { keys } => {
+                for (space, key) in keys {
+                    self.fmt_space(space);
+                    self.fmt_template(key);
+                }
+            }
             PredicateFuncValue::IsInteger => {}
             PredicateFuncValue::IsFloat => {}
             PredicateFuncValue::IsBoolean => {}
@@ -511,6 +639,8 @@ impl HtmlFormatter {
             PredicateFuncValue::IsDate => {}
             PredicateFuncValue::Exist => {}
             PredicateFuncValue::IsEmpty => {}
+            PredicateFuncValue::IsSorted => {}
+            PredicateFuncValue::IsSortedDescending => {}
         }
     }
 
@@ -739,6 +869,20 @@ impl HtmlFormatter {
 
     fn fmt_filter_value(&mut self, filter_value: &FilterValue) {
         match filter_value {
+            FilterValue::Abs => self.fmt_span("filter-type", "abs"),
+            FilterValue::Base64Encode => self.fmt_span("filter-type", "base64Encode"),
+            FilterValue::Base64UrlDecode => self.fmt_span("filter-type", "base64UrlDecode"),
+            FilterValue::Ceil => self.fmt_span("filter-type", "ceil"),
+            FilterValue::Coalesce { values } => {
+                self.fmt_span("filter-type", "coalesce");
+                for (space, value) in values {
+                    self.fmt_space(space);
+                    match (value.delimiter, value.elements.as_slice()) {
+                        (None, [TemplateElement::Expression(expr)]) => self.fmt_expr(expr),
+                        _ => self.fmt_template(value),
+                    }
+                }
+            }
             FilterValue::Count => self.fmt_span("filter-type", "count"),
             FilterValue::DaysAfterNow => self.fmt_span("filter-type", "daysAfterNow"),
             FilterValue::DaysBeforeNow => self.fmt_span("filter-type", "daysBeforeNow"),
@@ -747,18 +891,74 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(encoding);
             }
+            FilterValue::Drop { space0, n } => {
+                self.fmt_span("filter-type", "drop");
+                self.fmt_space(space0);
+                self.fmt_number(n);
+            }
+            FilterValue::FirstMatch { space0, predicate } => {
+                self.fmt_span("filter-type", "firstMatch");
+                self.fmt_space(space0);
+                self.fmt_predicate(predicate);
+            }
+            FilterValue::Floor => self.fmt_span("filter-type", "floor"),
+            FilterValue::FormDecode => self.fmt_span("filter-type", "formDecode"),
             FilterValue::Format { space0, fmt } => {
                 self.fmt_span("filter-type", "format");
                 self.fmt_space(space0);
                 self.fmt_template(fmt);
             }
+            FilterValue::HmacSha256 { space0, key } => {
+                self.fmt_span("filter-type", "hmacSha256");
+                self.fmt_space(space0);
+                self.fmt_predicate_value(key);
+            }
             FilterValue::HtmlEscape => self.fmt_span("filter-type", "htmlEscape"),
             FilterValue::HtmlUnescape => self.fmt_span("filter-type", "htmlUnescape"),
+            FilterValue::LastMatch { space0, predicate } => {
+                self.fmt_span("filter-type", "lastMatch");
+                self.fmt_space(space0);
+                self.fmt_predicate(predicate);
+            }
+            FilterValue::IndexOf { space0, needle } => {
+                self.fmt_span("filter-type", "indexOf");
+                self.fmt_space(space0);
+                self.fmt_predicate_value(needle);
+            }
+            FilterValue::JsonPath { space0, expr } => {
+                self.fmt_span("filter-type", "jsonpath");
+                self.fmt_space(space0);
+                self.fmt_template(expr);
+            }
+            FilterValue::Mask {
+                space0,
+                keep_start,
+                space1,
+                keep_end,
+            } => {
+                self.fmt_span("filter-type", "mask");
+                self.fmt_space(space0);
+                self.fmt_number(keep_start);
+                self.fmt_space(space1);
+                self.fmt_number(keep_end);
+            }
+            FilterValue::Normalize { space0, form } => {
+                self.fmt_span("filter-type", "normalize");
+                self.fmt_space(space0);
+                self.fmt_template(form);
+            }
             FilterValue::Nth { space0, n: value } => {
                 self.fmt_span("filter-type", "nth");
                 self.fmt_space(space0);
                 self.fmt_number(value);
             }
+            FilterValue::ParseUrl => self.fmt_span("filter-type", "parseUrl"),
+            FilterValue::PemToDer => self.fmt_span("filter-type", "pemToDer"),
+            FilterValue::QueryParam { space0, name } => {
+                self.fmt_span("filter-type", "queryParam");
+                self.fmt_space(space0);
+                self.fmt_template(name);
+            }
             FilterValue::Regex { space0, value } => {
                 self.fmt_span("filter-type", "regex");
                 self.fmt_space(space0);
@@ -776,17 +976,49 @@ impl HtmlFormatter {
                 self.fmt_space(space1);
                 self.fmt_template(new_value);
             }
+            FilterValue::Round { decimals } => {
+                self.fmt_span("filter-type", "round");
+                if let Some((space0, n)) = decimals {
+                    self.fmt_space(space0);
+                    self.fmt_number(n);
+                }
+            }
+            FilterValue::Join { space0, sep } => {
+                self.fmt_span("filter-type", "join");
+                self.fmt_space(space0);
+                self.fmt_template(sep);
+            }
+            FilterValue::Sha256 => self.fmt_span("filter-type", "sha256"),
             FilterValue::Split { space0, sep } => {
                 self.fmt_span("filter-type", "split");
                 self.fmt_space(space0);
                 self.fmt_template(sep);
             }
+            FilterValue::Take { space0, n } => {
+                self.fmt_span("filter-type", "take");
+                self.fmt_space(space0);
+                self.fmt_number(n);
+            }
             FilterValue::ToDate { space0, fmt } => {
                 self.fmt_span("filter-type", "toDate");
                 self.fmt_space(space0);
                 self.fmt_template(fmt);
             }
+            FilterValue::ToHex => self.fmt_span("filter-type", "toHex"),
             FilterValue::ToInt => self.fmt_span("filter-type", "toInt"),
+            FilterValue::Truncate {
+                space0,
+                n,
+                ellipsis,
+            } => {
+                self.fmt_span("filter-type", "truncate");
+                self.fmt_space(space0);
+                self.fmt_number(n);
+                if let Some((space1, ellipsis)) = ellipsis {
+                    self.fmt_space(space1);
+                    self.fmt_template(ellipsis);
+                }
+            }
             FilterValue::UrlDecode => self.fmt_span("filter-type", "urlDecode"),
             FilterValue::UrlEncode => self.fmt_span("filter-type", "urlEncode"),
             FilterValue::XPath { space0, expr } => {
@@ -794,6 +1026,11 @@ impl HtmlFormatter {
                 self.fmt_space(space0);
                 self.fmt_template(expr);
             }
+            FilterValue::Zip { space0, expr } => {
+                self.fmt_span("filter-type", "zip");
+                self.fmt_space(space0);
+                self.fmt_expr(expr);
+            }
         };
     }
 
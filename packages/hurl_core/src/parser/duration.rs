@@ -0,0 +1,144 @@
+/*
+ * Hurl (https://hurl.dev)
+ * Copyright (C) 2023 Orange
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *          http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ *
+ */
+use crate::ast::*;
+use crate::parser::error::*;
+use crate::parser::primitives::natural;
+use crate::parser::reader::Reader;
+use crate::parser::ParseResult;
+
+/// Parses a duration: a natural number, with an optional unit (`ms`, `s` or `m`).
+pub fn duration(reader: &mut Reader) -> ParseResult<Duration> {
+    let start = reader.state.clone();
+    let value = natural(reader).map_err(|e| Error {
+        inner: ParseError::InvalidDuration,
+        ..e
+    })?;
+    let unit_start = reader.state.clone();
+    let unit = reader.read_while(|c| c.is_ascii_alphabetic());
+    let unit = match unit.as_str() {
+        "" => None,
+        "ms" => Some(DurationUnit::MilliSecond),
+        "s" => Some(DurationUnit::Second),
+        "m" => Some(DurationUnit::Minute),
+        _ => {
+            return Err(Error {
+                pos: unit_start.pos,
+                recoverable: false,
+                inner: ParseError::InvalidDurationUnit(unit),
+            })
+        }
+    };
+    // Minutes are converted to seconds, check that they fit.
+    if unit == Some(DurationUnit::Minute) && value.checked_mul(60).is_none() {
+        return Err(Error {
+            pos: start.pos,
+            recoverable: false,
+            inner: ParseError::DurationOverflow,
+        });
+    }
+    Ok(Duration::new(value, unit))
+}
+
+/// Parses a full string `s` as a duration, for instance `500ms`, `2s` or `1m`.
+pub fn parse_duration(s: &str) -> ParseResult<Duration> {
+    let mut reader = Reader::new(s);
+    let value = duration(&mut reader)?;
+    if !reader.is_eof() {
+        return Err(Error {
+            pos: reader.state.pos,
+            recoverable: false,
+            inner: ParseError::InvalidDuration,
+        });
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration() {
+        let mut reader = Reader::new("10");
+        assert_eq!(duration(&mut reader).unwrap(), Duration::new(10, None));
+
+        let mut reader = Reader::new("500ms\n");
+        assert_eq!(
+            duration(&mut reader).unwrap(),
+            Duration::new(500, Some(DurationUnit::MilliSecond))
+        );
+        assert_eq!(reader.state.cursor, 5);
+
+        let mut reader = Reader::new("2s");
+        assert_eq!(
+            duration(&mut reader).unwrap(),
+            Duration::new(2, Some(DurationUnit::Second))
+        );
+
+        let mut reader = Reader::new("1m # comment");
+        assert_eq!(
+            duration(&mut reader).unwrap(),
+            Duration::new(1, Some(DurationUnit::Minute))
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1000").unwrap(), Duration::new(1000, None));
+        assert_eq!(
+            parse_duration("30s").unwrap(),
+            Duration::new(30, Some(DurationUnit::Second))
+        );
+        assert_eq!(
+            parse_duration("30s ").err().unwrap().inner,
+            ParseError::InvalidDuration
+        );
+        assert_eq!(
+            parse_duration("-1").err().unwrap().inner,
+            ParseError::InvalidDuration
+        );
+    }
+
+    #[test]
+    fn test_duration_error() {
+        let mut reader = Reader::new("2min");
+        let error = duration(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 2 });
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::InvalidDurationUnit("min".to_string())
+        );
+
+        let mut reader = Reader::new("s");
+        let error = duration(&mut reader).err().unwrap();
+        assert_eq!(error.inner, ParseError::InvalidDuration);
+
+        let mut reader = Reader::new("307445734561825860m");
+        assert_eq!(
+            duration(&mut reader).unwrap(),
+            Duration::new(307445734561825860, Some(DurationUnit::Minute))
+        );
+
+        let mut reader = Reader::new("307445734561825861m");
+        let error = duration(&mut reader).err().unwrap();
+        assert_eq!(error.pos, Pos { line: 1, column: 1 });
+        assert!(!error.recoverable);
+        assert_eq!(error.inner, ParseError::DurationOverflow);
+    }
+}
@@ -62,6 +62,9 @@ pub enum ParseError {
     UrlIllegalCharacter(char),
     UrlInvalidStart,
     InvalidOption,
+    InvalidDuration,
+    InvalidDurationUnit(String),
+    DurationOverflow,
     Multiline,
     GraphQlVariables,
 }
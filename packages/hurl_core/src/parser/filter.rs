@@ -15,8 +15,11 @@
  * limitations under the License.
  *
  */
-use crate::ast::{Filter, FilterValue, SourceInfo, Whitespace};
-use crate::parser::combinators::choice;
+use crate::ast::{Filter, FilterValue, SourceInfo, Template, TemplateElement, Whitespace};
+use crate::parser::combinators::{choice, optional};
+use crate::parser::expr;
+use crate::parser::predicate::predicate;
+use crate::parser::predicate_value::predicate_value;
 use crate::parser::primitives::{natural, one_or_more_spaces, try_literal, zero_or_more_spaces};
 use crate::parser::query::regex_value;
 use crate::parser::string::quoted_template;
@@ -51,22 +54,47 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     let start = reader.state.pos.clone();
     let value = choice(
         &[
+            abs_filter,
+            base64_encode_filter,
+            base64_url_decode_filter,
+            ceil_filter,
+            coalesce_filter,
             count_filter,
             days_after_now_filter,
             days_before_now_filter,
             decode_filter,
+            drop_filter,
+            first_match_filter,
+            floor_filter,
+            form_decode_filter,
             format_filter,
+            hmac_sha256_filter,
             html_decode_filter,
             html_encode_filter,
+            index_of_filter,
+            join_filter,
+            jsonpath_filter,
+            last_match_filter,
+            mask_filter,
+            normalize_filter,
             nth_filter,
+            parse_url_filter,
+            pem_to_der_filter,
+            query_param_filter,
             regex_filter,
             replace_filter,
+            round_filter,
+            sha256_filter,
             split_filter,
+            take_filter,
+            to_hex_filter,
             to_int_filter,
+            truncate_filter,
             to_date_filter,
             url_decode_filter,
             url_encode_filter,
             xpath_filter,
+            zip_filter,
         ],
         reader,
     )
@@ -88,6 +116,71 @@ pub fn filter(reader: &mut Reader) -> ParseResult<Filter> {
     Ok(Filter { source_info, value })
 }
 
+fn abs_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("abs", reader)?;
+    Ok(FilterValue::Abs)
+}
+
+fn base64_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64Encode", reader)?;
+    Ok(FilterValue::Base64Encode)
+}
+
+fn base64_url_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("base64UrlDecode", reader)?;
+    Ok(FilterValue::Base64UrlDecode)
+}
+
+fn ceil_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("ceil", reader)?;
+    Ok(FilterValue::Ceil)
+}
+
+fn coalesce_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("coalesce", reader)?;
+    let mut values = vec![];
+    loop {
+        let save = reader.state.clone();
+        let space = if values.is_empty() {
+            one_or_more_spaces(reader)?
+        } else {
+            match one_or_more_spaces(reader) {
+                Ok(space) => space,
+                Err(_) => {
+                    reader.state = save;
+                    break;
+                }
+            }
+        };
+        match coalesce_value(reader) {
+            Ok(value) => values.push((space, value)),
+            Err(e) if e.recoverable && !values.is_empty() => {
+                reader.state = save;
+                break;
+            }
+            Err(e) => return Err(e.non_recoverable()),
+        }
+    }
+    Ok(FilterValue::Coalesce { values })
+}
+
+/// Parses a `coalesce` argument: a quoted string or a single unquoted expression.
+fn coalesce_value(reader: &mut Reader) -> ParseResult<Template> {
+    let start = reader.state.pos.clone();
+    match expr::parse(reader) {
+        Ok(expr) => {
+            let end = reader.state.pos.clone();
+            Ok(Template {
+                delimiter: None,
+                elements: vec![TemplateElement::Expression(expr)],
+                source_info: SourceInfo { start, end },
+            })
+        }
+        Err(e) if e.recoverable => quoted_template(reader),
+        Err(e) => Err(e),
+    }
+}
+
 fn count_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("count", reader)?;
     Ok(FilterValue::Count)
@@ -110,6 +203,30 @@ fn decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Decode { space0, encoding })
 }
 
+fn drop_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("drop", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let n = natural(reader)?;
+    Ok(FilterValue::Drop { space0, n })
+}
+
+fn first_match_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("firstMatch", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let predicate = predicate(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::FirstMatch { space0, predicate })
+}
+
+fn floor_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("floor", reader)?;
+    Ok(FilterValue::Floor)
+}
+
+fn form_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("formDecode", reader)?;
+    Ok(FilterValue::FormDecode)
+}
+
 fn format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("format", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -117,6 +234,21 @@ fn format_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Format { space0, fmt })
 }
 
+fn hmac_sha256_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("hmacSha256", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let save = reader.state.clone();
+    let key = predicate_value(reader).map_err(|e| e.non_recoverable())?;
+    if !key.is_string() && !key.is_bytearray() {
+        return Err(Error {
+            pos: save.pos,
+            recoverable: false,
+            inner: ParseError::PredicateValue,
+        });
+    }
+    Ok(FilterValue::HmacSha256 { space0, key })
+}
+
 fn html_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("htmlEscape", reader)?;
     Ok(FilterValue::HtmlEscape)
@@ -127,6 +259,55 @@ fn html_decode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::HtmlUnescape)
 }
 
+fn index_of_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("indexOf", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let needle = predicate_value(reader)?;
+    Ok(FilterValue::IndexOf { space0, needle })
+}
+
+fn join_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("join", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let sep = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Join { space0, sep })
+}
+
+fn jsonpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("jsonpath", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::JsonPath { space0, expr })
+}
+
+fn last_match_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("lastMatch", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let predicate = predicate(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::LastMatch { space0, predicate })
+}
+
+fn mask_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("mask", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let keep_start = natural(reader)?;
+    let space1 = one_or_more_spaces(reader)?;
+    let keep_end = natural(reader)?;
+    Ok(FilterValue::Mask {
+        space0,
+        keep_start,
+        space1,
+        keep_end,
+    })
+}
+
+fn normalize_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("normalize", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let form = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Normalize { space0, form })
+}
+
 fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("nth", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -134,6 +315,23 @@ fn nth_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Nth { space0, n })
 }
 
+fn parse_url_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("parseUrl", reader)?;
+    Ok(FilterValue::ParseUrl)
+}
+
+fn pem_to_der_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("pemToDer", reader)?;
+    Ok(FilterValue::PemToDer)
+}
+
+fn query_param_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("queryParam", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::QueryParam { space0, name })
+}
+
 fn regex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("regex", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -155,6 +353,24 @@ fn replace_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     })
 }
 
+fn round_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("round", reader)?;
+    let decimals = optional(
+        |p1| {
+            let space0 = one_or_more_spaces(p1)?;
+            let n = natural(p1)?;
+            Ok((space0, n))
+        },
+        reader,
+    )?;
+    Ok(FilterValue::Round { decimals })
+}
+
+fn sha256_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("sha256", reader)?;
+    Ok(FilterValue::Sha256)
+}
+
 fn split_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("split", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -162,6 +378,13 @@ fn split_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::Split { space0, sep })
 }
 
+fn take_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("take", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let n = natural(reader)?;
+    Ok(FilterValue::Take { space0, n })
+}
+
 fn to_date_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("toDate", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -169,11 +392,35 @@ fn to_date_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::ToDate { space0, fmt })
 }
 
+fn to_hex_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("toHex", reader)?;
+    Ok(FilterValue::ToHex)
+}
+
 fn to_int_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("toInt", reader)?;
     Ok(FilterValue::ToInt)
 }
 
+fn truncate_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("truncate", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let n = natural(reader)?;
+    let ellipsis = optional(
+        |p1| {
+            let space1 = one_or_more_spaces(p1)?;
+            let ellipsis = quoted_template(p1)?;
+            Ok((space1, ellipsis))
+        },
+        reader,
+    )?;
+    Ok(FilterValue::Truncate {
+        space0,
+        n,
+        ellipsis,
+    })
+}
+
 fn url_encode_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     try_literal("urlEncode", reader)?;
     Ok(FilterValue::UrlEncode)
@@ -191,12 +438,87 @@ fn xpath_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
     Ok(FilterValue::XPath { space0, expr })
 }
 
+fn zip_filter(reader: &mut Reader) -> ParseResult<FilterValue> {
+    try_literal("zip", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let expr = expr::parse(reader).map_err(|e| e.non_recoverable())?;
+    Ok(FilterValue::Zip { space0, expr })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::Pos;
+    use crate::ast::{
+        Expr, Hex, Pos, Predicate, PredicateFunc, PredicateFuncValue, PredicateValue, Variable,
+    };
     use crate::parser::ParseError;
 
+    #[test]
+    fn test_coalesce() {
+        let mut reader = Reader::new("coalesce {{a}} \"b\" count");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 19),
+                value: FilterValue::Coalesce {
+                    values: vec![
+                        (
+                            Whitespace {
+                                value: " ".to_string(),
+                                source_info: SourceInfo::new(1, 9, 1, 10),
+                            },
+                            Template {
+                                delimiter: None,
+                                elements: vec![TemplateElement::Expression(Expr {
+                                    space0: Whitespace {
+                                        value: String::new(),
+                                        source_info: SourceInfo::new(1, 12, 1, 12),
+                                    },
+                                    variable: Variable {
+                                        name: "a".to_string(),
+                                        source_info: SourceInfo::new(1, 12, 1, 13),
+                                    },
+                                    space1: Whitespace {
+                                        value: String::new(),
+                                        source_info: SourceInfo::new(1, 13, 1, 13),
+                                    },
+                                })],
+                                source_info: SourceInfo::new(1, 10, 1, 15),
+                            }
+                        ),
+                        (
+                            Whitespace {
+                                value: " ".to_string(),
+                                source_info: SourceInfo::new(1, 15, 1, 16),
+                            },
+                            Template {
+                                delimiter: Some('"'),
+                                elements: vec![TemplateElement::String {
+                                    value: "b".to_string(),
+                                    encoded: "b".to_string(),
+                                }],
+                                source_info: SourceInfo::new(1, 16, 1, 19),
+                            }
+                        ),
+                    ],
+                },
+            }
+        );
+        // The next filter is not consumed
+        assert_eq!(reader.state.cursor, 18);
+
+        let mut reader = Reader::new("coalesce count");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.pos,
+            Pos {
+                line: 1,
+                column: 10
+            }
+        );
+        assert!(!err.recoverable);
+    }
+
     #[test]
     fn test_count() {
         let mut reader = Reader::new("count");
@@ -209,6 +531,283 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_round() {
+        let mut reader = Reader::new("round 2");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 8),
+                value: FilterValue::Round {
+                    decimals: Some((
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(1, 6, 1, 7),
+                        },
+                        2
+                    )),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("round == 3");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 6),
+                value: FilterValue::Round { decimals: None },
+            }
+        );
+        assert_eq!(reader.state.cursor, 5);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut reader = Reader::new("truncate 5 \"...\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 17),
+                value: FilterValue::Truncate {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    n: 5,
+                    ellipsis: Some((
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(1, 11, 1, 12),
+                        },
+                        Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "...".to_string(),
+                                encoded: "...".to_string(),
+                            }],
+                            source_info: SourceInfo::new(1, 12, 1, 17),
+                        }
+                    )),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("truncate 5 == \"abcde\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 11),
+                value: FilterValue::Truncate {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    n: 5,
+                    ellipsis: None,
+                },
+            }
+        );
+        assert_eq!(reader.state.cursor, 10);
+    }
+
+    #[test]
+    fn test_form_decode() {
+        let mut reader = Reader::new("formDecode");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 11),
+                value: FilterValue::FormDecode,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_url() {
+        let mut reader = Reader::new("parseUrl");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 9),
+                value: FilterValue::ParseUrl,
+            }
+        );
+    }
+
+    #[test]
+    fn test_first_match() {
+        let mut reader = Reader::new("firstMatch startsWith \"a\" == \"alice\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 26),
+                value: FilterValue::FirstMatch {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 11, 1, 12),
+                    },
+                    predicate: Predicate {
+                        not: false,
+                        space0: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(1, 12, 1, 12),
+                        },
+                        predicate_func: PredicateFunc {
+                            source_info: SourceInfo::new(1, 12, 1, 26),
+                            value: PredicateFuncValue::StartWith {
+                                space0: Whitespace {
+                                    value: " ".to_string(),
+                                    source_info: SourceInfo::new(1, 22, 1, 23),
+                                },
+                                value: PredicateValue::String(Template {
+                                    delimiter: Some('"'),
+                                    elements: vec![TemplateElement::String {
+                                        value: "a".to_string(),
+                                        encoded: "a".to_string(),
+                                    }],
+                                    source_info: SourceInfo::new(1, 23, 1, 26),
+                                }),
+                            },
+                        },
+                    },
+                },
+            }
+        );
+        assert_eq!(reader.state.cursor, 25);
+
+        let mut reader = Reader::new("lastMatch xxx");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.pos,
+            Pos {
+                line: 1,
+                column: 11
+            }
+        );
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        let mut reader = Reader::new("hmacSha256 hex,4a656665;");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 25),
+                value: FilterValue::HmacSha256 {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 11, 1, 12),
+                    },
+                    key: PredicateValue::Hex(Hex {
+                        space0: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(1, 16, 1, 16),
+                        },
+                        value: b"Jefe".to_vec(),
+                        encoded: "4a656665".to_string(),
+                        space1: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(1, 24, 1, 24),
+                        },
+                    }),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("hmacSha256 1");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(
+            err.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert_eq!(err.inner, ParseError::PredicateValue);
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_index_of() {
+        let mut reader = Reader::new("indexOf \"b\"");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 12),
+                value: FilterValue::IndexOf {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 8, 1, 9),
+                    },
+                    needle: PredicateValue::String(Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: "b".to_string(),
+                            encoded: "b".to_string(),
+                        }],
+                        source_info: SourceInfo::new(1, 9, 1, 12),
+                    }),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("indexOf 1");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 10),
+                value: FilterValue::IndexOf {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 8, 1, 9),
+                    },
+                    needle: PredicateValue::Integer(1),
+                },
+            }
+        );
+
+        let mut reader = Reader::new("indexOf xx");
+        let err = filter(&mut reader).err().unwrap();
+        assert_eq!(err.inner, ParseError::PredicateValue);
+        assert!(!err.recoverable);
+    }
+
+    #[test]
+    fn test_zip() {
+        let mut reader = Reader::new("zip {{names}}");
+        assert_eq!(
+            filter(&mut reader).unwrap(),
+            Filter {
+                source_info: SourceInfo::new(1, 1, 1, 14),
+                value: FilterValue::Zip {
+                    space0: Whitespace {
+                        value: " ".to_string(),
+                        source_info: SourceInfo::new(1, 4, 1, 5),
+                    },
+                    expr: Expr {
+                        space0: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(1, 7, 1, 7),
+                        },
+                        variable: Variable {
+                            name: "names".to_string(),
+                            source_info: SourceInfo::new(1, 7, 1, 12),
+                        },
+                        space1: Whitespace {
+                            value: String::new(),
+                            source_info: SourceInfo::new(1, 12, 1, 12),
+                        },
+                    },
+                },
+            }
+        );
+
+        let mut reader = Reader::new("zip \"names\"");
+        let err = filter(&mut reader).err().unwrap();
+        assert!(!err.recoverable);
+    }
+
     #[test]
     fn test_error() {
         let mut reader = Reader::new("xcount");
@@ -23,6 +23,7 @@ pub fn parse_hurl_file(s: &str) -> ParseResult<HurlFile> {
     parsers::hurl_file(&mut reader)
 }
 
+pub use self::duration::parse_duration;
 pub use self::error::{Error, ParseError};
 pub use self::json::{
     boolean_value as parse_json_boolean, null_value as parse_json_null,
@@ -36,6 +37,7 @@ mod base64;
 mod bytes;
 mod combinators;
 mod cookiepath;
+mod duration;
 mod error;
 mod expr;
 mod filename;
@@ -21,6 +21,7 @@ use crate::parser::error::*;
 use crate::parser::predicate_value::predicate_value;
 use crate::parser::primitives::*;
 use crate::parser::reader::Reader;
+use crate::parser::string::quoted_template;
 use crate::parser::ParseResult;
 
 pub fn predicate(reader: &mut Reader) -> ParseResult<Predicate> {
@@ -81,6 +82,8 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             contain_predicate,
             include_predicate,
             match_predicate,
+            one_of_predicate,
+            has_exactly_keys_predicate,
             integer_predicate,
             float_predicate,
             boolean_predicate,
@@ -89,6 +92,8 @@ fn predicate_func_value(reader: &mut Reader) -> ParseResult<PredicateFuncValue>
             date_predicate,
             exist_predicate,
             is_empty_predicate,
+            is_sorted_descending_predicate,
+            is_sorted_predicate,
         ],
         reader,
     ) {
@@ -331,6 +336,70 @@ fn match_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::Match { space0, value })
 }
 
+fn one_of_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("oneOf", reader)?;
+    let mut values = vec![];
+    loop {
+        let save = reader.state.clone();
+        let space = if values.is_empty() {
+            one_or_more_spaces(reader)?
+        } else {
+            match one_or_more_spaces(reader) {
+                Ok(space) => space,
+                Err(_) => {
+                    reader.state = save;
+                    break;
+                }
+            }
+        };
+        let start = reader.state.clone();
+        match predicate_value(reader) {
+            Ok(value) => values.push((space, value)),
+            // No more value: the spaces belong to what follows the predicate.
+            Err(e)
+                if !values.is_empty()
+                    && e.inner == ParseError::PredicateValue
+                    && e.pos == start.pos =>
+            {
+                reader.state = save;
+                break;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(PredicateFuncValue::OneOf { values })
+}
+
+fn has_exactly_keys_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("hasExactlyKeys", reader)?;
+    let mut keys = vec![];
+    loop {
+        let save = reader.state.clone();
+        let space = if keys.is_empty() {
+            one_or_more_spaces(reader)?
+        } else {
+            match one_or_more_spaces(reader) {
+                Ok(space) => space,
+                Err(_) => {
+                    reader.state = save;
+                    break;
+                }
+            }
+        };
+        let start = reader.state.clone();
+        match quoted_template(reader) {
+            Ok(key) => keys.push((space, key)),
+            // No more key: the spaces belong to what follows the predicate.
+            Err(e) if !keys.is_empty() && e.recoverable && e.pos == start.pos => {
+                reader.state = save;
+                break;
+            }
+            Err(e) => return Err(e.non_recoverable()),
+        }
+    }
+    Ok(PredicateFuncValue::HasExactlyKeys { keys })
+}
+
 fn integer_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     try_literal("isInteger", reader)?;
     Ok(PredicateFuncValue::IsInteger)
@@ -371,6 +440,16 @@ fn is_empty_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
     Ok(PredicateFuncValue::IsEmpty)
 }
 
+fn is_sorted_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isSorted", reader)?;
+    Ok(PredicateFuncValue::IsSorted)
+}
+
+fn is_sorted_descending_predicate(reader: &mut Reader) -> ParseResult<PredicateFuncValue> {
+    try_literal("isSortedDescending", reader)?;
+    Ok(PredicateFuncValue::IsSortedDescending)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -550,6 +629,80 @@ mod tests {
         assert_eq!(error.inner, ParseError::PredicateValue);
     }
 
+    #[test]
+    fn test_one_of_predicate() {
+        let mut reader = Reader::new("oneOf 200  204 # comment");
+        assert_eq!(
+            one_of_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::OneOf {
+                values: vec![
+                    (
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(1, 6, 1, 7),
+                        },
+                        PredicateValue::Integer(200)
+                    ),
+                    (
+                        Whitespace {
+                            value: "  ".to_string(),
+                            source_info: SourceInfo::new(1, 10, 1, 12),
+                        },
+                        PredicateValue::Integer(204)
+                    ),
+                ],
+            }
+        );
+        assert_eq!(reader.state.cursor, 14);
+
+        let mut reader = Reader::new("oneOf");
+        assert!(one_of_predicate(&mut reader).is_err());
+    }
+
+    #[test]
+    fn test_has_exactly_keys_predicate() {
+        let mut reader = Reader::new("hasExactlyKeys \"id\" \"name\" # comment");
+        assert_eq!(
+            has_exactly_keys_predicate(&mut reader).unwrap(),
+            PredicateFuncValue::HasExactlyKeys {
+                keys: vec![
+                    (
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(1, 15, 1, 16),
+                        },
+                        Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "id".to_string(),
+                                encoded: "id".to_string(),
+                            }],
+                            source_info: SourceInfo::new(1, 16, 1, 20),
+                        }
+                    ),
+                    (
+                        Whitespace {
+                            value: " ".to_string(),
+                            source_info: SourceInfo::new(1, 20, 1, 21),
+                        },
+                        Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "name".to_string(),
+                                encoded: "name".to_string(),
+                            }],
+                            source_info: SourceInfo::new(1, 21, 1, 27),
+                        }
+                    ),
+                ],
+            }
+        );
+        assert_eq!(reader.state.cursor, 26);
+
+        let mut reader = Reader::new("hasExactlyKeys 1");
+        assert!(has_exactly_keys_predicate(&mut reader).is_err());
+    }
+
     #[test]
     fn test_date_predicate() {
         let mut reader = Reader::new("isDate");
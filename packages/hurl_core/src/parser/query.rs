@@ -37,19 +37,34 @@ fn query_value(reader: &mut Reader) -> ParseResult<QueryValue> {
     choice(
         &[
             status_query,
+            reason_phrase_query,
             url_query,
             header_query,
+            pseudo_header_query,
+            cookie_nth_query,
             cookie_query,
+            body_length_query,
             body_query,
+            compression_ratio_query,
+            content_length_query,
+            content_type_query,
+            freshness_lifetime_query,
+            is_fresh_query,
             xpath_query,
             jsonpath_query,
             regex_query,
             variable_query,
             duration_query,
+            retries_query,
             bytes_query,
             sha256_query,
             md5_query,
             certificate_query,
+            tls_resumed_query,
+            transfer_query,
+            redirects_query,
+            redirect_query,
+            request_query,
         ],
         reader,
     )
@@ -60,6 +75,11 @@ fn status_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Status)
 }
 
+fn reason_phrase_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("reasonPhrase", reader)?;
+    Ok(QueryValue::ReasonPhrase)
+}
+
 fn url_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("url", reader)?;
     Ok(QueryValue::Url)
@@ -72,6 +92,13 @@ fn header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Header { space0, name })
 }
 
+fn pseudo_header_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("pseudoHeader", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let name = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+    Ok(QueryValue::PseudoHeader { space0, name })
+}
+
 fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("cookie", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -90,11 +117,75 @@ fn cookie_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Cookie { space0, expr })
 }
 
+fn cookie_nth_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("cookie", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    try_literal("nth", reader)?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.non_recoverable())?;
+    let index = natural(reader).map_err(|e| e.non_recoverable())?;
+    let space2 = one_or_more_spaces(reader).map_err(|e| e.non_recoverable())?;
+    let attribute = cookie_nth_attribute(reader)?;
+    Ok(QueryValue::CookieNth {
+        space0,
+        space1,
+        index,
+        space2,
+        attribute,
+    })
+}
+
+fn cookie_nth_attribute(reader: &mut Reader) -> ParseResult<CookieNthAttribute> {
+    if try_literal("name", reader).is_ok() {
+        Ok(CookieNthAttribute::Name)
+    } else if try_literal("value", reader).is_ok() {
+        Ok(CookieNthAttribute::Value)
+    } else {
+        let value = "Cookie attribute <name> or <value>".to_string();
+        let inner = ParseError::Expecting { value };
+        let pos = reader.state.pos.clone();
+        Err(Error {
+            pos,
+            recoverable: false,
+            inner,
+        })
+    }
+}
+
 fn body_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("body", reader)?;
     Ok(QueryValue::Body)
 }
 
+fn body_length_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("bodyLength", reader)?;
+    Ok(QueryValue::BodyLength)
+}
+
+fn compression_ratio_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("compressionRatio", reader)?;
+    Ok(QueryValue::CompressionRatio)
+}
+
+fn content_length_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("contentLength", reader)?;
+    Ok(QueryValue::ContentLength)
+}
+
+fn content_type_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("contentType", reader)?;
+    Ok(QueryValue::ContentType)
+}
+
+fn freshness_lifetime_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("freshnessLifetime", reader)?;
+    Ok(QueryValue::FreshnessLifetime)
+}
+
+fn is_fresh_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("isFresh", reader)?;
+    Ok(QueryValue::IsFresh)
+}
+
 fn xpath_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("xpath", reader)?;
     let space0 = one_or_more_spaces(reader)?;
@@ -165,6 +256,11 @@ fn duration_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     Ok(QueryValue::Duration)
 }
 
+fn retries_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("retries", reader)?;
+    Ok(QueryValue::Retries)
+}
+
 fn bytes_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     try_literal("bytes", reader)?;
     Ok(QueryValue::Bytes)
@@ -190,6 +286,11 @@ fn certificate_query(reader: &mut Reader) -> ParseResult<QueryValue> {
     })
 }
 
+fn tls_resumed_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("tlsResumed", reader)?;
+    Ok(QueryValue::TlsResumed)
+}
+
 fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeName> {
     literal("\"", reader)?;
     if try_literal(r#"Subject""#, reader).is_ok() {
@@ -202,9 +303,114 @@ fn certificate_field(reader: &mut Reader) -> ParseResult<CertificateAttributeNam
         Ok(CertificateAttributeName::ExpireDate)
     } else if try_literal(r#"Serial-Number""#, reader).is_ok() {
         Ok(CertificateAttributeName::SerialNumber)
+    } else if try_literal(r#"Chain-Length""#, reader).is_ok() {
+        Ok(CertificateAttributeName::ChainLength)
+    } else if try_literal(r#"Self-Signed""#, reader).is_ok() {
+        Ok(CertificateAttributeName::SelfSigned)
+    } else if try_literal(r#"OCSP-Status""#, reader).is_ok() {
+        Ok(CertificateAttributeName::OcspStatus)
+    } else if try_literal(r#"Pem""#, reader).is_ok() {
+        Ok(CertificateAttributeName::Pem)
     } else {
-        let value =
-            "Field <Subject>, <Issuer>,<Start-Date>, <Expire-Date> or <Serial-Number>".to_string();
+        let value = "Field <Subject>, <Issuer>,<Start-Date>, <Expire-Date>, <Serial-Number>, \
+                     <Chain-Length>, <Self-Signed>, <OCSP-Status> or <Pem>"
+            .to_string();
+        let inner = ParseError::Expecting { value };
+        let pos = reader.state.pos.clone();
+        Err(Error {
+            pos,
+            recoverable: false,
+            inner,
+        })
+    }
+}
+
+fn request_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("request", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let field = request_field(reader)?;
+    Ok(QueryValue::Request {
+        space0,
+        attribute_name: field,
+    })
+}
+
+fn request_field(reader: &mut Reader) -> ParseResult<RequestAttributeName> {
+    literal("\"", reader)?;
+    if try_literal(r#"Version""#, reader).is_ok() {
+        Ok(RequestAttributeName::Version)
+    } else if try_literal(r#"Line""#, reader).is_ok() {
+        Ok(RequestAttributeName::Line)
+    } else {
+        let value = "Field <Version> or <Line>".to_string();
+        let inner = ParseError::Expecting { value };
+        let pos = reader.state.pos.clone();
+        Err(Error {
+            pos,
+            recoverable: false,
+            inner,
+        })
+    }
+}
+
+fn transfer_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("transfer", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let field = transfer_field(reader)?;
+    Ok(QueryValue::Transfer {
+        space0,
+        attribute_name: field,
+    })
+}
+
+fn transfer_field(reader: &mut Reader) -> ParseResult<TransferAttributeName> {
+    literal("\"", reader)?;
+    if try_literal(r#"Size-Download""#, reader).is_ok() {
+        Ok(TransferAttributeName::SizeDownload)
+    } else if try_literal(r#"Speed-Download""#, reader).is_ok() {
+        Ok(TransferAttributeName::SpeedDownload)
+    } else if try_literal(r#"Speed-Upload""#, reader).is_ok() {
+        Ok(TransferAttributeName::SpeedUpload)
+    } else {
+        let value = "Field <Size-Download>, <Speed-Download> or <Speed-Upload>".to_string();
+        let inner = ParseError::Expecting { value };
+        let pos = reader.state.pos.clone();
+        Err(Error {
+            pos,
+            recoverable: false,
+            inner,
+        })
+    }
+}
+
+fn redirects_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("redirects", reader)?;
+    Ok(QueryValue::Redirects)
+}
+
+fn redirect_query(reader: &mut Reader) -> ParseResult<QueryValue> {
+    try_literal("redirect", reader)?;
+    let space0 = one_or_more_spaces(reader)?;
+    let index = natural(reader).map_err(|e| e.non_recoverable())?;
+    let space1 = one_or_more_spaces(reader).map_err(|e| e.non_recoverable())?;
+    let attribute = redirect_attribute(reader)?;
+    Ok(QueryValue::Redirect {
+        space0,
+        index,
+        space1,
+        attribute,
+    })
+}
+
+fn redirect_attribute(reader: &mut Reader) -> ParseResult<RedirectAttribute> {
+    if try_literal("status", reader).is_ok() {
+        Ok(RedirectAttribute::Status)
+    } else if try_literal("header", reader).is_ok() {
+        let space0 = one_or_more_spaces(reader).map_err(|e| e.non_recoverable())?;
+        let name = quoted_template(reader).map_err(|e| e.non_recoverable())?;
+        Ok(RedirectAttribute::Header { space0, name })
+    } else {
+        let value = "Redirect attribute <status> or <header>".to_string();
         let inner = ParseError::Expecting { value };
         let pos = reader.state.pos.clone();
         Err(Error {
@@ -266,6 +472,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pseudo_header_query() {
+        let mut reader = Reader::new("pseudoHeader \":status\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 23),
+                value: QueryValue::PseudoHeader {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 13, 1, 14),
+                    },
+                    name: Template {
+                        delimiter: Some('"'),
+                        elements: vec![TemplateElement::String {
+                            value: ":status".to_string(),
+                            encoded: ":status".to_string(),
+                        }],
+                        source_info: SourceInfo::new(1, 14, 1, 23),
+                    },
+                },
+            }
+        );
+    }
+
     #[test]
     fn test_cookie_query() {
         let mut reader = Reader::new("cookie \"Foo[Domain]\"");
@@ -305,6 +536,44 @@ mod tests {
         //let mut reader = Reader::init("cookie \"cookie\u{31}\"");
     }
 
+    #[test]
+    fn test_cookie_nth_query() {
+        let mut reader = Reader::new("cookie nth 1 value");
+        assert_eq!(
+            cookie_nth_query(&mut reader).unwrap(),
+            QueryValue::CookieNth {
+                space0: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 7, 1, 8),
+                },
+                space1: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 11, 1, 12),
+                },
+                index: 1,
+                space2: Whitespace {
+                    value: String::from(" "),
+                    source_info: SourceInfo::new(1, 13, 1, 14),
+                },
+                attribute: CookieNthAttribute::Value,
+            }
+        );
+        assert_eq!(reader.state.cursor, 18);
+
+        let mut reader = Reader::new("cookie \"Foo\"");
+        assert!(cookie_nth_query(&mut reader).unwrap_err().recoverable);
+
+        let mut reader = Reader::new("cookie nth 1 expires");
+        let error = cookie_nth_query(&mut reader).unwrap_err();
+        assert!(!error.recoverable);
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "Cookie attribute <name> or <value>".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_xpath_query() {
         let mut reader = Reader::new("xpath \"normalize-space(//head/title)\"");
@@ -383,6 +652,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_query() {
+        let mut reader = Reader::new("request \"Version\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 18),
+                value: QueryValue::Request {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 8, 1, 9),
+                    },
+                    attribute_name: RequestAttributeName::Version,
+                },
+            }
+        );
+
+        let mut reader = Reader::new("request \"Method\"");
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "Field <Version> or <Line>".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_transfer_query() {
+        let mut reader = Reader::new("transfer \"Size-Download\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 25),
+                value: QueryValue::Transfer {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    attribute_name: TransferAttributeName::SizeDownload,
+                },
+            }
+        );
+
+        let mut reader = Reader::new("transfer \"Size\"");
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "Field <Size-Download>, <Speed-Download> or <Speed-Upload>".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
+    #[test]
+    fn test_redirects_query() {
+        let mut reader = Reader::new("redirects");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 10),
+                value: QueryValue::Redirects,
+            }
+        );
+    }
+
+    #[test]
+    fn test_redirect_query() {
+        let mut reader = Reader::new("redirect 1 status");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 18),
+                value: QueryValue::Redirect {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    index: 1,
+                    space1: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 11, 1, 12),
+                    },
+                    attribute: RedirectAttribute::Status,
+                },
+            }
+        );
+
+        let mut reader = Reader::new("redirect 0 header \"Location\"");
+        assert_eq!(
+            query(&mut reader).unwrap(),
+            Query {
+                source_info: SourceInfo::new(1, 1, 1, 29),
+                value: QueryValue::Redirect {
+                    space0: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 9, 1, 10),
+                    },
+                    index: 0,
+                    space1: Whitespace {
+                        value: String::from(" "),
+                        source_info: SourceInfo::new(1, 11, 1, 12),
+                    },
+                    attribute: RedirectAttribute::Header {
+                        space0: Whitespace {
+                            value: String::from(" "),
+                            source_info: SourceInfo::new(1, 18, 1, 19),
+                        },
+                        name: Template {
+                            delimiter: Some('"'),
+                            elements: vec![TemplateElement::String {
+                                value: "Location".to_string(),
+                                encoded: "Location".to_string(),
+                            }],
+                            source_info: SourceInfo::new(1, 19, 1, 29),
+                        },
+                    },
+                },
+            }
+        );
+
+        let mut reader = Reader::new("redirect 1 foo");
+        let error = query(&mut reader).err().unwrap();
+        assert_eq!(
+            error.pos,
+            Pos {
+                line: 1,
+                column: 12
+            }
+        );
+        assert_eq!(
+            error.inner,
+            ParseError::Expecting {
+                value: "Redirect attribute <status> or <header>".to_string()
+            }
+        );
+        assert!(!error.recoverable);
+    }
+
     #[test]
     fn test_query_with_filters() {
         let mut reader = Reader::new("body urlDecode ");
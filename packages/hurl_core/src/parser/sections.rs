@@ -17,6 +17,7 @@
  */
 use crate::ast::*;
 use crate::parser::combinators::*;
+use crate::parser::duration::duration;
 use crate::parser::error::*;
 use crate::parser::filter::filters;
 use crate::parser::predicate::predicate;
@@ -367,12 +368,14 @@ fn option(reader: &mut Reader) -> ParseResult<EntryOption> {
         "cacert" => option_cacert(reader)?,
         "cert" => option_cert(reader)?,
         "compressed" => option_compressed(reader)?,
+        "connect-timeout" => option_connect_timeout(reader)?,
         "connect-to" => option_connect_to(reader)?,
         "delay" => option_delay(reader)?,
         "key" => option_key(reader)?,
         "insecure" => option_insecure(reader)?,
         "location" => option_follow_location(reader)?,
         "max-redirs" => option_max_redirect(reader)?,
+        "max-time" => option_max_time(reader)?,
         "path-as-is" => option_path_as_is(reader)?,
         "proxy" => option_proxy(reader)?,
         "resolve" => option_resolve(reader)?,
@@ -380,7 +383,9 @@ fn option(reader: &mut Reader) -> ParseResult<EntryOption> {
         "retry-interval" => option_retry_interval(reader)?,
         "variable" => option_variable(reader)?,
         "verbose" => option_verbose(reader)?,
+        "verify-status" => option_verify_status(reader)?,
         "very-verbose" => option_very_verbose(reader)?,
+        "websocket" => option_websocket(reader)?,
         _ => {
             return Err(Error {
                 pos,
@@ -416,13 +421,18 @@ fn option_cert(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::ClientCert(value))
 }
 
+fn option_connect_timeout(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(duration, reader)?;
+    Ok(OptionKind::ConnectTimeout(value))
+}
+
 fn option_connect_to(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = connect_to(reader)?;
     Ok(OptionKind::ConnectTo(value))
 }
 
 fn option_delay(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = nonrecover(natural, reader)?;
+    let value = nonrecover(duration, reader)?;
     Ok(OptionKind::Delay(value))
 }
 
@@ -454,6 +464,11 @@ fn option_max_redirect(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::MaxRedirect(value))
 }
 
+fn option_max_time(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(duration, reader)?;
+    Ok(OptionKind::MaxTime(value))
+}
+
 fn option_path_as_is(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean, reader)?;
     Ok(OptionKind::PathAsIs(value))
@@ -475,7 +490,7 @@ fn option_retry(reader: &mut Reader) -> ParseResult<OptionKind> {
 }
 
 fn option_retry_interval(reader: &mut Reader) -> ParseResult<OptionKind> {
-    let value = nonrecover(natural, reader)?;
+    let value = nonrecover(duration, reader)?;
     Ok(OptionKind::RetryInterval(value))
 }
 
@@ -489,11 +504,21 @@ fn option_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
     Ok(OptionKind::Verbose(value))
 }
 
+fn option_verify_status(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean, reader)?;
+    Ok(OptionKind::VerifyStatus(value))
+}
+
 fn option_very_verbose(reader: &mut Reader) -> ParseResult<OptionKind> {
     let value = nonrecover(boolean, reader)?;
     Ok(OptionKind::VeryVerbose(value))
 }
 
+fn option_websocket(reader: &mut Reader) -> ParseResult<OptionKind> {
+    let value = nonrecover(boolean, reader)?;
+    Ok(OptionKind::WebSocket(value))
+}
+
 fn aws_sigv4(reader: &mut Reader) -> ParseResult<String> {
     let start = reader.state.clone();
     let provider = reader.read_while(|c| c.is_alphanumeric() || *c == ':' || *c == '-');
@@ -886,6 +911,43 @@ mod tests {
         assert!(!error.recoverable)
     }
 
+    #[test]
+    fn test_option_durations() {
+        let formats = [
+            ("10", Duration::new(10, None)),
+            ("500ms", Duration::new(500, Some(DurationUnit::MilliSecond))),
+            ("2s", Duration::new(2, Some(DurationUnit::Second))),
+            ("1m", Duration::new(1, Some(DurationUnit::Minute))),
+        ];
+        for name in ["connect-timeout", "delay", "max-time", "retry-interval"] {
+            for (text, duration) in formats {
+                let mut reader = Reader::new(&format!("{name}: {text}"));
+                let kind = option(&mut reader).unwrap().kind;
+                assert_eq!(kind.name(), name);
+                let expected = match name {
+                    "connect-timeout" => OptionKind::ConnectTimeout(duration),
+                    "delay" => OptionKind::Delay(duration),
+                    "max-time" => OptionKind::MaxTime(duration),
+                    _ => OptionKind::RetryInterval(duration),
+                };
+                assert_eq!(kind, expected);
+            }
+
+            let mut reader = Reader::new(&format!("{name}: 10min"));
+            let error = option(&mut reader).err().unwrap();
+            assert!(!error.recoverable);
+            assert_eq!(
+                error.inner,
+                ParseError::InvalidDurationUnit("min".to_string())
+            );
+
+            let mut reader = Reader::new(&format!("{name}: 307445734561825861m"));
+            let error = option(&mut reader).err().unwrap();
+            assert!(!error.recoverable);
+            assert_eq!(error.inner, ParseError::DurationOverflow);
+        }
+    }
+
     #[test]
     fn test_option_cacert() {
         let mut reader = Reader::new("cacert: /home/foo/cert.pem");
@@ -285,20 +285,24 @@ impl ToJson for EntryOption {
             OptionKind::ClientKey(filename) => JValue::String(filename.value.clone()),
             OptionKind::Compressed(value) => JValue::Boolean(*value),
             OptionKind::ConnectTo(value) => JValue::String(value.clone()),
-            OptionKind::Delay(value) => JValue::Number(value.to_string()),
+            OptionKind::ConnectTimeout(value) => value.to_json(),
+            OptionKind::Delay(value) => value.to_json(),
             OptionKind::Insecure(value) => JValue::Boolean(*value),
             OptionKind::FollowLocation(value) => JValue::Boolean(*value),
             OptionKind::MaxRedirect(value) => JValue::Number(value.to_string()),
+            OptionKind::MaxTime(value) => value.to_json(),
             OptionKind::PathAsIs(value) => JValue::Boolean(*value),
             OptionKind::Proxy(value) => JValue::String(value.clone()),
             OptionKind::Resolve(value) => JValue::String(value.clone()),
             OptionKind::Retry(value) => JValue::Number(value.to_string()),
-            OptionKind::RetryInterval(value) => JValue::Number(value.to_string()),
+            OptionKind::RetryInterval(value) => value.to_json(),
             OptionKind::Variable(value) => {
                 JValue::String(format!("{}={}", value.name, value.value))
             }
             OptionKind::Verbose(value) => JValue::Boolean(*value),
+            OptionKind::VerifyStatus(value) => JValue::Boolean(*value),
             OptionKind::VeryVerbose(value) => JValue::Boolean(*value),
+            OptionKind::WebSocket(value) => JValue::Boolean(*value),
         };
         attributes.push((name, value));
 
@@ -306,6 +310,15 @@ impl ToJson for EntryOption {
     }
 }
 
+impl ToJson for Duration {
+    fn to_json(&self) -> JValue {
+        match self.unit {
+            None => JValue::Number(self.value.to_string()),
+            Some(_) => JValue::String(self.to_string()),
+        }
+    }
+}
+
 impl ToJson for Capture {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![
@@ -345,20 +358,70 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Status => {
             attributes.push(("type".to_string(), JValue::String("status".to_string())));
         }
+        QueryValue::ReasonPhrase => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("reasonPhrase".to_string()),
+            ));
+        }
         QueryValue::Url => {
             attributes.push(("type".to_string(), JValue::String("url".to_string())));
         }
         QueryValue::Body => {
             attributes.push(("type".to_string(), JValue::String("body".to_string())));
         }
+        QueryValue::BodyLength => {
+            attributes.push(("type".to_string(), JValue::String("bodyLength".to_string())));
+        }
+        QueryValue::CompressionRatio => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("compressionRatio".to_string()),
+            ));
+        }
+        QueryValue::ContentLength => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("contentLength".to_string()),
+            ));
+        }
+        QueryValue::ContentType => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("contentType".to_string()),
+            ));
+        }
+        QueryValue::FreshnessLifetime => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("freshnessLifetime".to_string()),
+            ));
+        }
+        QueryValue::IsFresh => {
+            attributes.push(("type".to_string(), JValue::String("isFresh".to_string())));
+        }
         QueryValue::Jsonpath { expr, .. } => {
             attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
         }
+        QueryValue::CookieNth {
+            index, attribute, ..
+        } => {
+            attributes.push(("type".to_string(), JValue::String("cookie".to_string())));
+            attributes.push(("index".to_string(), JValue::Number(index.to_string())));
+            attributes.push(("attribute".to_string(), attribute.to_json()));
+        }
         QueryValue::Header { name, .. } => {
             attributes.push(("type".to_string(), JValue::String("header".to_string())));
             attributes.push(("name".to_string(), JValue::String(name.to_string())));
         }
+        QueryValue::PseudoHeader { name, .. } => {
+            attributes.push((
+                "type".to_string(),
+                JValue::String("pseudo-header".to_string()),
+            ));
+            attributes.push(("name".to_string(), JValue::String(name.to_string())));
+        }
         QueryValue::Cookie { expr, .. } => {
             attributes.push(("type".to_string(), JValue::String("cookie".to_string())));
             attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
@@ -378,6 +441,9 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
         QueryValue::Duration => {
             attributes.push(("type".to_string(), JValue::String("duration".to_string())));
         }
+        QueryValue::Retries => {
+            attributes.push(("type".to_string(), JValue::String("retries".to_string())));
+        }
         QueryValue::Bytes => {
             attributes.push(("type".to_string(), JValue::String("bytes".to_string())));
         }
@@ -397,6 +463,33 @@ fn query_value_attributes(query_value: &QueryValue) -> Vec<(String, JValue)> {
             ));
             attributes.push(("expr".to_string(), field.to_json()));
         }
+        QueryValue::TlsResumed => {
+            attributes.push(("type".to_string(), JValue::String("tlsResumed".to_string())));
+        }
+        QueryValue::Transfer {
+            attribute_name: field,
+            ..
+        } => {
+            attributes.push(("type".to_string(), JValue::String("transfer".to_string())));
+            attributes.push(("expr".to_string(), field.to_json()));
+        }
+        QueryValue::Redirects => {
+            attributes.push(("type".to_string(), JValue::String("redirects".to_string())));
+        }
+        QueryValue::Redirect {
+            index, attribute, ..
+        } => {
+            attributes.push(("type".to_string(), JValue::String("redirect".to_string())));
+            attributes.push(("index".to_string(), JValue::Number(index.to_string())));
+            attributes.push(("attribute".to_string(), attribute.to_json()));
+        }
+        QueryValue::Request {
+            attribute_name: field,
+            ..
+        } => {
+            attributes.push(("type".to_string(), JValue::String("request".to_string())));
+            attributes.push(("expr".to_string(), field.to_json()));
+        }
     };
     attributes
 }
@@ -428,11 +521,61 @@ impl ToJson for CertificateAttributeName {
             CertificateAttributeName::StartDate => "Start-Date",
             CertificateAttributeName::ExpireDate => "Expire-Date",
             CertificateAttributeName::SerialNumber => "Serial-Number",
+            CertificateAttributeName::ChainLength => "Chain-Length",
+            CertificateAttributeName::SelfSigned => "Self-Signed",
+            CertificateAttributeName::OcspStatus => "OCSP-Status",
+            CertificateAttributeName::Pem => "Pem",
+        };
+        JValue::String(value.to_string())
+    }
+}
+
+impl ToJson for TransferAttributeName {
+    fn to_json(&self) -> JValue {
+        let value = match self {
+            TransferAttributeName::SizeDownload => "Size-Download",
+            TransferAttributeName::SpeedDownload => "Speed-Download",
+            TransferAttributeName::SpeedUpload => "Speed-Upload",
+        };
+        JValue::String(value.to_string())
+    }
+}
+
+impl ToJson for RequestAttributeName {
+    fn to_json(&self) -> JValue {
+        let value = match self {
+            RequestAttributeName::Version => "Version",
+            RequestAttributeName::Line => "Line",
+        };
+        JValue::String(value.to_string())
+    }
+}
+
+impl ToJson for CookieNthAttribute {
+    fn to_json(&self) -> JValue {
+        let value = match self {
+            CookieNthAttribute::Name => "name",
+            CookieNthAttribute::Value => "value",
         };
         JValue::String(value.to_string())
     }
 }
 
+impl ToJson for RedirectAttribute {
+    fn to_json(&self) -> JValue {
+        match self {
+            RedirectAttribute::Status => JValue::String("status".to_string()),
+            RedirectAttribute::Header { name, .. } => {
+                let attributes = vec![
+                    ("type".to_string(), JValue::String("header".to_string())),
+                    ("name".to_string(), JValue::String(name.to_string())),
+                ];
+                JValue::Object(attributes)
+            }
+        }
+    }
+}
+
 impl ToJson for Predicate {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
@@ -490,6 +633,25 @@ impl ToJson for Predicate {
                 attributes.push(("type".to_string(), JValue::String("match".to_string())));
                 add_predicate_value(&mut attributes, value);
             }
+            PredicateFuncValue::OneOf { values } => {
+                attributes.push(("type".to_string(), JValue::String("one-of".to_string())));
+                let values = values
+                    .into_iter()
+                    .map(|(_, value)| json_predicate_value(value).0)
+                    .collect();
+                attributes.push(("values".to_string(), JValue::List(values)));
+            }
+            PredicateFuncValue::HasExactlyKeys { keys } => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("has-exactly-keys".to_string()),
+                ));
+                let keys = keys
+                    .into_iter()
+                    .map(|(_, key)| JValue::String(key.to_string()))
+                    .collect();
+                attributes.push(("keys".to_string(), JValue::List(keys)));
+            }
             PredicateFuncValue::IsInteger => {
                 attributes.push(("type".to_string(), JValue::String("isInteger".to_string())));
             }
@@ -517,6 +679,15 @@ impl ToJson for Predicate {
             PredicateFuncValue::IsEmpty => {
                 attributes.push(("type".to_string(), JValue::String("isEmpty".to_string())));
             }
+            PredicateFuncValue::IsSorted => {
+                attributes.push(("type".to_string(), JValue::String("isSorted".to_string())));
+            }
+            PredicateFuncValue::IsSortedDescending => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("isSortedDescending".to_string()),
+                ));
+            }
         }
         JValue::Object(attributes)
     }
@@ -590,6 +761,32 @@ impl ToJson for FilterValue {
     fn to_json(&self) -> JValue {
         let mut attributes = vec![];
         match self {
+            FilterValue::Abs => {
+                attributes.push(("type".to_string(), JValue::String("abs".to_string())));
+            }
+            FilterValue::Base64Encode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64Encode".to_string()),
+                ));
+            }
+            FilterValue::Base64UrlDecode => {
+                attributes.push((
+                    "type".to_string(),
+                    JValue::String("base64UrlDecode".to_string()),
+                ));
+            }
+            FilterValue::Ceil => {
+                attributes.push(("type".to_string(), JValue::String("ceil".to_string())));
+            }
+            FilterValue::Coalesce { values } => {
+                attributes.push(("type".to_string(), JValue::String("coalesce".to_string())));
+                let values = values
+                    .iter()
+                    .map(|(_, value)| JValue::String(value.to_string()))
+                    .collect();
+                attributes.push(("values".to_string(), JValue::List(values)));
+            }
             FilterValue::Count => {
                 attributes.push(("type".to_string(), JValue::String("count".to_string())));
             }
@@ -609,14 +806,72 @@ impl ToJson for FilterValue {
                 attributes.push(("type".to_string(), JValue::String("decode".to_string())));
                 attributes.push(("encoding".to_string(), JValue::String(encoding.to_string())));
             }
+            FilterValue::Drop { n, .. } => {
+                attributes.push(("type".to_string(), JValue::String("drop".to_string())));
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
+            FilterValue::FirstMatch { predicate, .. } => {
+                attributes.push(("type".to_string(), JValue::String("firstMatch".to_string())));
+                attributes.push(("predicate".to_string(), predicate.to_json()));
+            }
+            FilterValue::Floor => {
+                attributes.push(("type".to_string(), JValue::String("floor".to_string())));
+            }
+            FilterValue::FormDecode => {
+                attributes.push(("type".to_string(), JValue::String("formDecode".to_string())));
+            }
             FilterValue::Format { fmt, .. } => {
                 attributes.push(("type".to_string(), JValue::String("format".to_string())));
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::LastMatch { predicate, .. } => {
+                attributes.push(("type".to_string(), JValue::String("lastMatch".to_string())));
+                attributes.push(("predicate".to_string(), predicate.to_json()));
+            }
+            FilterValue::IndexOf { needle, .. } => {
+                attributes.push(("type".to_string(), JValue::String("indexOf".to_string())));
+                let (needle, encoding) = json_predicate_value(needle.clone());
+                attributes.push(("needle".to_string(), needle));
+                if let Some(encoding) = encoding {
+                    attributes.push(("encoding".to_string(), JValue::String(encoding)));
+                }
+            }
+            FilterValue::Join { sep, .. } => {
+                attributes.push(("type".to_string(), JValue::String("join".to_string())));
+                attributes.push(("sep".to_string(), JValue::String(sep.to_string())));
+            }
+            FilterValue::JsonPath { expr, .. } => {
+                attributes.push(("type".to_string(), JValue::String("jsonpath".to_string())));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
+            FilterValue::Mask {
+                keep_start,
+                keep_end,
+                ..
+            } => {
+                attributes.push(("type".to_string(), JValue::String("mask".to_string())));
+                attributes.push((
+                    "keep_start".to_string(),
+                    JValue::Number(keep_start.to_string()),
+                ));
+                attributes.push(("keep_end".to_string(), JValue::Number(keep_end.to_string())));
+            }
+            FilterValue::Normalize { form, .. } => {
+                attributes.push(("type".to_string(), JValue::String("normalize".to_string())));
+                attributes.push(("form".to_string(), JValue::String(form.to_string())));
+            }
             FilterValue::Nth { n, .. } => {
                 attributes.push(("type".to_string(), JValue::String("nth".to_string())));
                 attributes.push(("n".to_string(), JValue::Number(n.to_string())));
             }
+            FilterValue::HmacSha256 { key, .. } => {
+                attributes.push(("type".to_string(), JValue::String("hmacSha256".to_string())));
+                let (key, encoding) = json_predicate_value(key.clone());
+                attributes.push(("key".to_string(), key));
+                if let Some(encoding) = encoding {
+                    attributes.push(("encoding".to_string(), JValue::String(encoding)));
+                }
+            }
             FilterValue::HtmlEscape => {
                 attributes.push(("type".to_string(), JValue::String("htmlEscape".to_string())));
             }
@@ -626,6 +881,16 @@ impl ToJson for FilterValue {
                     JValue::String("htmlUnescape".to_string()),
                 ));
             }
+            FilterValue::ParseUrl => {
+                attributes.push(("type".to_string(), JValue::String("parseUrl".to_string())));
+            }
+            FilterValue::PemToDer => {
+                attributes.push(("type".to_string(), JValue::String("pemToDer".to_string())));
+            }
+            FilterValue::QueryParam { name, .. } => {
+                attributes.push(("type".to_string(), JValue::String("queryParam".to_string())));
+                attributes.push(("name".to_string(), JValue::String(name.to_string())));
+            }
             FilterValue::Regex { value, .. } => {
                 attributes.push(("type".to_string(), JValue::String("regex".to_string())));
                 attributes.push(("expr".to_string(), value.to_json()));
@@ -642,27 +907,54 @@ impl ToJson for FilterValue {
                     JValue::String(new_value.to_string()),
                 ));
             }
+            FilterValue::Round { decimals } => {
+                attributes.push(("type".to_string(), JValue::String("round".to_string())));
+                if let Some((_, n)) = decimals {
+                    attributes.push(("decimals".to_string(), JValue::Number(n.to_string())));
+                }
+            }
             FilterValue::UrlEncode => {
                 attributes.push(("type".to_string(), JValue::String("urlEncode".to_string())));
             }
             FilterValue::UrlDecode => {
                 attributes.push(("type".to_string(), JValue::String("urlDecode".to_string())));
             }
+            FilterValue::Sha256 => {
+                attributes.push(("type".to_string(), JValue::String("sha256".to_string())));
+            }
             FilterValue::Split { sep, .. } => {
                 attributes.push(("type".to_string(), JValue::String("split".to_string())));
                 attributes.push(("sep".to_string(), JValue::String(sep.to_string())));
             }
+            FilterValue::Take { n, .. } => {
+                attributes.push(("type".to_string(), JValue::String("take".to_string())));
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+            }
             FilterValue::ToDate { fmt, .. } => {
                 attributes.push(("type".to_string(), JValue::String("toDate".to_string())));
                 attributes.push(("fmt".to_string(), JValue::String(fmt.to_string())));
             }
+            FilterValue::ToHex => {
+                attributes.push(("type".to_string(), JValue::String("toHex".to_string())));
+            }
             FilterValue::ToInt => {
                 attributes.push(("type".to_string(), JValue::String("toInt".to_string())));
             }
+            FilterValue::Truncate { n, ellipsis, .. } => {
+                attributes.push(("type".to_string(), JValue::String("truncate".to_string())));
+                attributes.push(("n".to_string(), JValue::Number(n.to_string())));
+                if let Some((_, ellipsis)) = ellipsis {
+                    attributes.push(("ellipsis".to_string(), JValue::String(ellipsis.to_string())));
+                }
+            }
             FilterValue::XPath { expr, .. } => {
                 attributes.push(("type".to_string(), JValue::String("toDate".to_string())));
                 attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
             }
+            FilterValue::Zip { expr, .. } => {
+                attributes.push(("type".to_string(), JValue::String("zip".to_string())));
+                attributes.push(("expr".to_string(), JValue::String(expr.to_string())));
+            }
         }
         JValue::Object(attributes)
     }
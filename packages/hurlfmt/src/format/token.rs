@@ -404,12 +404,18 @@ impl Tokenizable for QueryValue {
         let mut tokens: Vec<Token> = vec![];
         match self.clone() {
             QueryValue::Status => tokens.push(Token::QueryType(String::from("status"))),
+            QueryValue::ReasonPhrase => tokens.push(Token::QueryType(String::from("reasonPhrase"))),
             QueryValue::Url => tokens.push(Token::QueryType(String::from("url"))),
             QueryValue::Header { space0, name } => {
                 tokens.push(Token::QueryType(String::from("header")));
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut name.tokenize());
             }
+            QueryValue::PseudoHeader { space0, name } => {
+                tokens.push(Token::QueryType(String::from("pseudoHeader")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+            }
             QueryValue::Cookie { space0, expr } => {
                 tokens.push(Token::QueryType(String::from("cookie")));
                 tokens.append(&mut space0.tokenize());
@@ -417,7 +423,34 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut expr.tokenize());
                 tokens.push(Token::CodeDelimiter("\"".to_string()));
             }
+            QueryValue::CookieNth {
+                space0,
+                space1,
+                index,
+                space2,
+                attribute,
+            } => {
+                tokens.push(Token::QueryType(String::from("cookie")));
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::QueryType(String::from("nth")));
+                tokens.append(&mut space1.tokenize());
+                tokens.push(Token::Number(index.to_string()));
+                tokens.append(&mut space2.tokenize());
+                tokens.append(&mut attribute.tokenize());
+            }
             QueryValue::Body => tokens.push(Token::QueryType(String::from("body"))),
+            QueryValue::BodyLength => tokens.push(Token::QueryType(String::from("bodyLength"))),
+            QueryValue::CompressionRatio => {
+                tokens.push(Token::QueryType(String::from("compressionRatio")))
+            }
+            QueryValue::ContentLength => {
+                tokens.push(Token::QueryType(String::from("contentLength")))
+            }
+            QueryValue::ContentType => tokens.push(Token::QueryType(String::from("contentType"))),
+            QueryValue::FreshnessLifetime => {
+                tokens.push(Token::QueryType(String::from("freshnessLifetime")))
+            }
+            QueryValue::IsFresh => tokens.push(Token::QueryType(String::from("isFresh"))),
             QueryValue::Xpath { space0, expr } => {
                 tokens.push(Token::QueryType(String::from("xpath")));
                 tokens.append(&mut space0.tokenize());
@@ -439,6 +472,7 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut name.tokenize());
             }
             QueryValue::Duration => tokens.push(Token::QueryType(String::from("duration"))),
+            QueryValue::Retries => tokens.push(Token::QueryType(String::from("retries"))),
             QueryValue::Bytes => tokens.push(Token::QueryType(String::from("bytes"))),
             QueryValue::Sha256 => tokens.push(Token::QueryType(String::from("sha256"))),
             QueryValue::Md5 => tokens.push(Token::QueryType(String::from("md5"))),
@@ -450,6 +484,36 @@ impl Tokenizable for QueryValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut field.tokenize());
             }
+            QueryValue::TlsResumed => tokens.push(Token::QueryType(String::from("tlsResumed"))),
+            QueryValue::Transfer {
+                space0,
+                attribute_name: field,
+            } => {
+                tokens.push(Token::QueryType(String::from("transfer")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut field.tokenize());
+            }
+            QueryValue::Redirects => tokens.push(Token::QueryType(String::from("redirects"))),
+            QueryValue::Redirect {
+                space0,
+                index,
+                space1,
+                attribute,
+            } => {
+                tokens.push(Token::QueryType(String::from("redirect")));
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(index.to_string()));
+                tokens.append(&mut space1.tokenize());
+                tokens.append(&mut attribute.tokenize());
+            }
+            QueryValue::Request {
+                space0,
+                attribute_name: field,
+            } => {
+                tokens.push(Token::QueryType(String::from("request")));
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut field.tokenize());
+            }
         }
         tokens
     }
@@ -494,11 +558,60 @@ impl Tokenizable for CertificateAttributeName {
             CertificateAttributeName::StartDate => "Start-Date",
             CertificateAttributeName::ExpireDate => "Expire-Date",
             CertificateAttributeName::SerialNumber => "Serial-Number",
+            CertificateAttributeName::ChainLength => "Chain-Length",
+            CertificateAttributeName::SelfSigned => "Self-Signed",
+            CertificateAttributeName::OcspStatus => "OCSP-Status",
+            CertificateAttributeName::Pem => "Pem",
+        };
+        vec![Token::String(value.to_string())]
+    }
+}
+
+impl Tokenizable for TransferAttributeName {
+    fn tokenize(&self) -> Vec<Token> {
+        let value = match self {
+            TransferAttributeName::SizeDownload => "Size-Download",
+            TransferAttributeName::SpeedDownload => "Speed-Download",
+            TransferAttributeName::SpeedUpload => "Speed-Upload",
         };
         vec![Token::String(value.to_string())]
     }
 }
 
+impl Tokenizable for RequestAttributeName {
+    fn tokenize(&self) -> Vec<Token> {
+        let value = match self {
+            RequestAttributeName::Version => "Version",
+            RequestAttributeName::Line => "Line",
+        };
+        vec![Token::String(value.to_string())]
+    }
+}
+
+impl Tokenizable for CookieNthAttribute {
+    fn tokenize(&self) -> Vec<Token> {
+        let value = match self {
+            CookieNthAttribute::Name => "name",
+            CookieNthAttribute::Value => "value",
+        };
+        vec![Token::QueryType(value.to_string())]
+    }
+}
+
+impl Tokenizable for RedirectAttribute {
+    fn tokenize(&self) -> Vec<Token> {
+        match self {
+            RedirectAttribute::Status => vec![Token::QueryType(String::from("status"))],
+            RedirectAttribute::Header { space0, name } => {
+                let mut tokens: Vec<Token> = vec![Token::QueryType(String::from("header"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+                tokens
+            }
+        }
+    }
+}
+
 impl Tokenizable for Predicate {
     fn tokenize(&self) -> Vec<Token> {
         let mut tokens: Vec<Token> = vec![];
@@ -576,6 +689,20 @@ impl Tokenizable for PredicateFuncValue {
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut value.tokenize());
             }
+            PredicateFuncValue::OneOf { values } => {
+                tokens.push(Token::PredicateType(self.name()));
+                for (space, value) in values {
+                    tokens.append(&mut space.tokenize());
+                    tokens.append(&mut value.tokenize());
+                }
+            }
+            PredicateFuncValue::HasExactlyKeys { keys } => {
+                tokens.push(Token::PredicateType(self.name()));
+                for (space, key) in keys {
+                    tokens.append(&mut space.tokenize());
+                    tokens.append(&mut key.tokenize());
+                }
+            }
 
             PredicateFuncValue::IsInteger => {
                 tokens.push(Token::PredicateType(self.name()));
@@ -601,6 +728,12 @@ impl Tokenizable for PredicateFuncValue {
             PredicateFuncValue::IsEmpty => {
                 tokens.push(Token::PredicateType(self.name()));
             }
+            PredicateFuncValue::IsSorted => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
+            PredicateFuncValue::IsSortedDescending => {
+                tokens.push(Token::PredicateType(self.name()));
+            }
         }
         tokens
     }
@@ -882,22 +1015,32 @@ impl Tokenizable for OptionKind {
             OptionKind::ClientKey(filename) => filename.tokenize(),
             OptionKind::Compressed(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::ConnectTo(value) => vec![Token::String(value.clone())],
-            OptionKind::Delay(value) => vec![Token::Number(value.to_string())],
+            OptionKind::ConnectTimeout(value) => value.tokenize(),
+            OptionKind::Delay(value) => value.tokenize(),
             OptionKind::Insecure(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::FollowLocation(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::MaxRedirect(value) => vec![Token::Number(value.to_string())],
+            OptionKind::MaxTime(value) => value.tokenize(),
             OptionKind::PathAsIs(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::Proxy(value) => vec![Token::String(value.clone())],
             OptionKind::Resolve(value) => vec![Token::String(value.clone())],
             OptionKind::Retry(value) => value.tokenize(),
-            OptionKind::RetryInterval(value) => vec![Token::Number(value.to_string())],
+            OptionKind::RetryInterval(value) => value.tokenize(),
             OptionKind::Variable(value) => value.tokenize(),
             OptionKind::Verbose(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::VerifyStatus(value) => vec![Token::Boolean(value.to_string())],
             OptionKind::VeryVerbose(value) => vec![Token::Boolean(value.to_string())],
+            OptionKind::WebSocket(value) => vec![Token::Boolean(value.to_string())],
         }
     }
 }
 
+impl Tokenizable for Duration {
+    fn tokenize(&self) -> Vec<Token> {
+        vec![Token::Number(self.to_string())]
+    }
+}
+
 impl Tokenizable for Retry {
     fn tokenize(&self) -> Vec<Token> {
         match self {
@@ -934,6 +1077,20 @@ impl Tokenizable for VariableValue {
 impl Tokenizable for Filter {
     fn tokenize(&self) -> Vec<Token> {
         match self.value.clone() {
+            FilterValue::Abs => vec![Token::FilterType(String::from("abs"))],
+            FilterValue::Base64Encode => vec![Token::FilterType(String::from("base64Encode"))],
+            FilterValue::Base64UrlDecode => {
+                vec![Token::FilterType(String::from("base64UrlDecode"))]
+            }
+            FilterValue::Ceil => vec![Token::FilterType(String::from("ceil"))],
+            FilterValue::Coalesce { values } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("coalesce"))];
+                for (space, value) in values {
+                    tokens.append(&mut space.tokenize());
+                    tokens.append(&mut value.tokenize());
+                }
+                tokens
+            }
             FilterValue::Count => vec![Token::FilterType(String::from("count"))],
             FilterValue::DaysAfterNow => vec![Token::FilterType(String::from("daysAfterNow"))],
             FilterValue::DaysBeforeNow => vec![Token::FilterType(String::from("daysBeforeNow"))],
@@ -943,22 +1100,87 @@ impl Tokenizable for Filter {
                 tokens.append(&mut encoding.tokenize());
                 tokens
             }
+            FilterValue::Drop { space0, n } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("drop"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(n.to_string()));
+                tokens
+            }
+            FilterValue::FirstMatch { space0, predicate } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("firstMatch"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut predicate.tokenize());
+                tokens
+            }
+            FilterValue::Floor => vec![Token::FilterType(String::from("floor"))],
+            FilterValue::FormDecode => vec![Token::FilterType(String::from("formDecode"))],
             FilterValue::Format { space0, fmt } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("format"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut fmt.tokenize());
                 tokens
             }
+            FilterValue::HmacSha256 { space0, key } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("hmacSha256"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut key.tokenize());
+                tokens
+            }
             FilterValue::HtmlEscape => vec![Token::FilterType(String::from("htmlEscape"))],
             FilterValue::HtmlUnescape => {
                 vec![Token::FilterType(String::from("htmlUnescape"))]
             }
+            FilterValue::LastMatch { space0, predicate } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("lastMatch"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut predicate.tokenize());
+                tokens
+            }
+            FilterValue::IndexOf { space0, needle } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("indexOf"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut needle.tokenize());
+                tokens
+            }
+            FilterValue::JsonPath { space0, expr } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("jsonpath"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens
+            }
+            FilterValue::Mask {
+                space0,
+                keep_start,
+                space1,
+                keep_end,
+            } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("mask"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(keep_start.to_string()));
+                tokens.append(&mut space1.tokenize());
+                tokens.push(Token::Number(keep_end.to_string()));
+                tokens
+            }
+            FilterValue::Normalize { space0, form } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("normalize"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut form.tokenize());
+                tokens
+            }
             FilterValue::Nth { space0, n } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("nth"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.push(Token::Number(n.to_string()));
                 tokens
             }
+            FilterValue::ParseUrl => vec![Token::FilterType(String::from("parseUrl"))],
+            FilterValue::PemToDer => vec![Token::FilterType(String::from("pemToDer"))],
+            FilterValue::QueryParam { space0, name } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("queryParam"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut name.tokenize());
+                tokens
+            }
             FilterValue::Regex { space0, value } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("regex"))];
                 tokens.append(&mut space0.tokenize());
@@ -980,25 +1202,67 @@ impl Tokenizable for Filter {
             }
             FilterValue::UrlEncode => vec![Token::FilterType(String::from("urlEncode"))],
             FilterValue::UrlDecode => vec![Token::FilterType(String::from("urlDecode"))],
+            FilterValue::Join { space0, sep } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("join"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut sep.tokenize());
+                tokens
+            }
+            FilterValue::Sha256 => vec![Token::FilterType(String::from("sha256"))],
             FilterValue::Split { space0, sep } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("split"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut sep.tokenize());
                 tokens
             }
+            FilterValue::Round { decimals } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("round"))];
+                if let Some((space0, n)) = decimals {
+                    tokens.append(&mut space0.tokenize());
+                    tokens.push(Token::Number(n.to_string()));
+                }
+                tokens
+            }
+            FilterValue::Take { space0, n } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("take"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(n.to_string()));
+                tokens
+            }
             FilterValue::ToDate { space0, fmt } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("toDate"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut fmt.tokenize());
                 tokens
             }
+            FilterValue::ToHex => vec![Token::FilterType(String::from("toHex"))],
             FilterValue::ToInt => vec![Token::FilterType(String::from("toInt"))],
+            FilterValue::Truncate {
+                space0,
+                n,
+                ellipsis,
+            } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("truncate"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.push(Token::Number(n.to_string()));
+                if let Some((space1, ellipsis)) = ellipsis {
+                    tokens.append(&mut space1.tokenize());
+                    tokens.append(&mut ellipsis.tokenize());
+                }
+                tokens
+            }
             FilterValue::XPath { space0, expr } => {
                 let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("xpath"))];
                 tokens.append(&mut space0.tokenize());
                 tokens.append(&mut expr.tokenize());
                 tokens
             }
+            FilterValue::Zip { space0, expr } => {
+                let mut tokens: Vec<Token> = vec![Token::FilterType(String::from("zip"))];
+                tokens.append(&mut space0.tokenize());
+                tokens.append(&mut expr.tokenize());
+                tokens
+            }
         }
     }
 }
@@ -235,11 +235,16 @@ fn lint_query(query: &Query) -> Query {
 fn lint_query_value(query_value: &QueryValue) -> QueryValue {
     match query_value {
         QueryValue::Status => QueryValue::Status,
+        QueryValue::ReasonPhrase => QueryValue::ReasonPhrase,
         QueryValue::Url => QueryValue::Url,
         QueryValue::Header { name, .. } => QueryValue::Header {
             name: name.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::PseudoHeader { name, .. } => QueryValue::PseudoHeader {
+            name: name.clone(),
+            space0: one_whitespace(),
+        },
         QueryValue::Cookie {
             expr: CookiePath { name, attribute },
             ..
@@ -253,7 +258,22 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
                 },
             }
         }
+        QueryValue::CookieNth {
+            index, attribute, ..
+        } => QueryValue::CookieNth {
+            space0: one_whitespace(),
+            space1: one_whitespace(),
+            index: *index,
+            space2: one_whitespace(),
+            attribute: attribute.clone(),
+        },
         QueryValue::Body => QueryValue::Body,
+        QueryValue::BodyLength => QueryValue::BodyLength,
+        QueryValue::CompressionRatio => QueryValue::CompressionRatio,
+        QueryValue::ContentLength => QueryValue::ContentLength,
+        QueryValue::ContentType => QueryValue::ContentType,
+        QueryValue::FreshnessLifetime => QueryValue::FreshnessLifetime,
+        QueryValue::IsFresh => QueryValue::IsFresh,
         QueryValue::Xpath { expr, .. } => QueryValue::Xpath {
             expr: expr.clone(),
             space0: one_whitespace(),
@@ -271,6 +291,7 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             space0: one_whitespace(),
         },
         QueryValue::Duration => QueryValue::Duration,
+        QueryValue::Retries => QueryValue::Retries,
         QueryValue::Bytes => QueryValue::Bytes,
         QueryValue::Sha256 => QueryValue::Sha256,
         QueryValue::Md5 => QueryValue::Md5,
@@ -281,6 +302,40 @@ fn lint_query_value(query_value: &QueryValue) -> QueryValue {
             attribute_name: field.clone(),
             space0: one_whitespace(),
         },
+        QueryValue::TlsResumed => QueryValue::TlsResumed,
+        QueryValue::Transfer {
+            attribute_name: field,
+            ..
+        } => QueryValue::Transfer {
+            attribute_name: field.clone(),
+            space0: one_whitespace(),
+        },
+        QueryValue::Redirects => QueryValue::Redirects,
+        QueryValue::Redirect {
+            index, attribute, ..
+        } => QueryValue::Redirect {
+            space0: one_whitespace(),
+            index: *index,
+            space1: one_whitespace(),
+            attribute: lint_redirect_attribute(attribute),
+        },
+        QueryValue::Request {
+            attribute_name: field,
+            ..
+        } => QueryValue::Request {
+            attribute_name: field.clone(),
+            space0: one_whitespace(),
+        },
+    }
+}
+
+fn lint_redirect_attribute(redirect_attribute: &RedirectAttribute) -> RedirectAttribute {
+    match redirect_attribute {
+        RedirectAttribute::Status => RedirectAttribute::Status,
+        RedirectAttribute::Header { name, .. } => RedirectAttribute::Header {
+            space0: one_whitespace(),
+            name: lint_template(name),
+        },
     }
 }
 
@@ -382,6 +437,18 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
             space0: one_whitespace(),
             value: lint_predicate_value(value),
         },
+        PredicateFuncValue::OneOf { values } => PredicateFuncValue::OneOf {
+            values: values
+                .iter()
+                .map(|(_, value)| (one_whitespace(), lint_predicate_value(value)))
+                .collect(),
+        },
+        PredicateFuncValue::HasExactlyKeys { keys } => PredicateFuncValue::HasExactlyKeys {
+            keys: keys
+                .iter()
+                .map(|(_, key)| (one_whitespace(), lint_template(key)))
+                .collect(),
+        },
         PredicateFuncValue::StartWith { value, .. } => PredicateFuncValue::StartWith {
             space0: one_whitespace(),
             value: lint_predicate_value(value),
@@ -398,6 +465,8 @@ fn lint_predicate_func_value(predicate_func_value: &PredicateFuncValue) -> Predi
         PredicateFuncValue::IsDate => PredicateFuncValue::IsDate,
         PredicateFuncValue::Exist => PredicateFuncValue::Exist,
         PredicateFuncValue::IsEmpty => PredicateFuncValue::IsEmpty,
+        PredicateFuncValue::IsSorted => PredicateFuncValue::IsSorted,
+        PredicateFuncValue::IsSortedDescending => PredicateFuncValue::IsSortedDescending,
     }
 }
 
@@ -635,10 +704,34 @@ fn lint_filter(filter: &Filter) -> Filter {
 
 fn lint_filter_value(filter_value: &FilterValue) -> FilterValue {
     match filter_value {
+        FilterValue::Coalesce { values } => FilterValue::Coalesce {
+            values: values
+                .iter()
+                .map(|(_, value)| (one_whitespace(), lint_template(value)))
+                .collect(),
+        },
         FilterValue::Regex { value, .. } => FilterValue::Regex {
             space0: one_whitespace(),
             value: lint_regex_value(value),
         },
+        FilterValue::FirstMatch { predicate, .. } => FilterValue::FirstMatch {
+            space0: one_whitespace(),
+            predicate: lint_predicate(predicate),
+        },
+        FilterValue::LastMatch { predicate, .. } => FilterValue::LastMatch {
+            space0: one_whitespace(),
+            predicate: lint_predicate(predicate),
+        },
+        FilterValue::Round { decimals } => FilterValue::Round {
+            decimals: decimals.as_ref().map(|(_, n)| (one_whitespace(), *n)),
+        },
+        FilterValue::Truncate { n, ellipsis, .. } => FilterValue::Truncate {
+            space0: one_whitespace(),
+            n: *n,
+            ellipsis: ellipsis
+                .as_ref()
+                .map(|(_, ellipsis)| (one_whitespace(), lint_template(ellipsis))),
+        },
         f => f.clone(),
     }
 }